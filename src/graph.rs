@@ -1,3 +1,15 @@
+pub mod background;
+pub use background::*;
+
+pub mod command;
+pub use command::*;
+
+pub mod descriptor;
+pub use descriptor::*;
+
+pub mod eval;
+pub use eval::*;
+
 pub mod editor;
 pub use editor::*;
 
@@ -7,5 +19,11 @@ pub use graph::*;
 pub mod node;
 pub use node::*;
 
+pub mod style;
+pub use style::*;
+
 pub mod value;
 pub use value::*;
+
+pub mod viewport;
+pub use viewport::*;
@@ -0,0 +1,101 @@
+use eframe::egui;
+use egui::{Color32, Pos2, Rect, Stroke, Vec2};
+
+/// Something drawn behind the nodes, before `node_order` is iterated. Gives
+/// large graphs a visual anchor for panning, and a place to hang custom
+/// canvas decoration.
+pub trait BackgroundPattern {
+    /// `viewport_rect` is the on-screen area the canvas occupies; `scale`
+    /// and `offset` are the current `ViewportState` transform, so patterns
+    /// can keep their world-space spacing fixed while the view pans/zooms.
+    fn draw(&self, viewport_rect: Rect, scale: f32, offset: Vec2, painter: &egui::Painter);
+}
+
+/// Minor grid lines every `spacing` world-units, with a heavier line every
+/// `thick_every` cells.
+#[derive(Clone, Copy, Debug)]
+pub struct Grid {
+    pub spacing: f32,
+    pub thick_every: usize,
+    pub thin_color: Color32,
+    pub thick_color: Color32,
+}
+
+impl Grid {
+    pub fn new(spacing: f32, thick_every: usize) -> Self {
+        Self {
+            spacing,
+            thick_every,
+            thin_color: Color32::from_gray(40),
+            thick_color: Color32::from_gray(60),
+        }
+    }
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Self::new(25.0, 5)
+    }
+}
+
+/// The on-screen position of every grid line covering `min..=max` along one
+/// axis, paired with whether it falls on a `thick_every`'th cell. Shared by
+/// both the column and row sweep in `Grid::draw`, and split out so the line
+/// layout can be unit tested without a live `egui::Painter`.
+pub(crate) fn grid_lines(min: f32, max: f32, origin: f32, cell: f32, thick_every: usize) -> Vec<(f32, bool)> {
+    let first = ((min - origin) / cell).floor() as i64;
+    let last = ((max - origin) / cell).ceil() as i64;
+    (first..=last)
+        .map(|i| {
+            let pos = origin + i as f32 * cell;
+            let thick = thick_every != 0 && i % thick_every as i64 == 0;
+            (pos, thick)
+        })
+        .collect()
+}
+
+impl BackgroundPattern for Grid {
+    fn draw(&self, viewport_rect: Rect, scale: f32, offset: Vec2, painter: &egui::Painter) {
+        let cell = self.spacing * scale;
+        if cell < 2.0 {
+            // Too dense to be useful (and expensive to paint); skip.
+            return;
+        }
+
+        // World-space origin (0, 0) maps to this screen position; must match
+        // `ViewportState::to_screen` exactly or the grid drifts relative to
+        // the nodes drawn on top of it.
+        let origin = offset * scale;
+
+        for (x, thick) in grid_lines(viewport_rect.left(), viewport_rect.right(), origin.x, cell, self.thick_every) {
+            let color = if thick { self.thick_color } else { self.thin_color };
+            painter.line_segment(
+                [Pos2::new(x, viewport_rect.top()), Pos2::new(x, viewport_rect.bottom())],
+                Stroke::new(if thick { 1.5 } else { 1.0 }, color),
+            );
+        }
+
+        for (y, thick) in grid_lines(viewport_rect.top(), viewport_rect.bottom(), origin.y, cell, self.thick_every) {
+            let color = if thick { self.thick_color } else { self.thin_color };
+            painter.line_segment(
+                [Pos2::new(viewport_rect.left(), y), Pos2::new(viewport_rect.right(), y)],
+                Stroke::new(if thick { 1.5 } else { 1.0 }, color),
+            );
+        }
+    }
+}
+
+/// Escape hatch for background decoration that doesn't fit `BackgroundPattern`
+/// as a named type — e.g. an app-specific texture or debug overlay.
+pub struct CustomBackground<F>(pub F)
+where
+    F: Fn(Rect, f32, Vec2, &egui::Painter);
+
+impl<F> BackgroundPattern for CustomBackground<F>
+where
+    F: Fn(Rect, f32, Vec2, &egui::Painter),
+{
+    fn draw(&self, viewport_rect: Rect, scale: f32, offset: Vec2, painter: &egui::Painter) {
+        (self.0)(viewport_rect, scale, offset, painter)
+    }
+}
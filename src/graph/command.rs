@@ -0,0 +1,465 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use eframe::egui;
+
+use super::graph::Graph;
+use super::node::{Input, Node, NodeId, Output, SlotId};
+
+/// Follows a remap table to its end, e.g. turning a `NodeId`/`SlotId` that
+/// was valid before some intervening remove-and-restore into the one that's
+/// actually live in the graph today. Used both by `CommandHistory::resolve`
+/// and by `Command::resolve_ids` implementations that need to chase the same
+/// tables before touching `graph`.
+fn follow<K: Eq + Hash + Copy>(map: &HashMap<K, K>, mut id: K) -> K {
+    while let Some(&remapped) = map.get(&id) {
+        id = remapped;
+    }
+    id
+}
+
+/// What a `Command::apply`/`undo` call caused `CommandHistory` to learn
+/// about ids that moved out from under it.
+#[derive(Default)]
+pub struct IdRemap {
+    /// A node came back under a new id (only `RemoveNode::undo` does this,
+    /// since slotmap hands out a fresh key on re-insertion).
+    pub node: Option<(NodeId, NodeId)>,
+    /// Every input/output slot that came back under a new id alongside the
+    /// node above — `RemoveNode::undo` re-inserts a node's whole slot set,
+    /// and slotmap hands each one a fresh key too.
+    pub slots: Vec<(SlotId, SlotId)>,
+}
+
+/// A single reversible mutation of a `Graph`. Pushed onto a `CommandHistory`
+/// as the user interacts with the editor, so every edit can be undone and
+/// redone without the editor having to special-case each `NodeResponse`.
+///
+/// `apply`/`undo` return an `IdRemap` when the step caused a node (and/or its
+/// slots) to come back under different ids than they held before — only
+/// `RemoveNode::undo` does this. `CommandHistory` folds that into its remap
+/// tables and, before running any other command later pulled off a stack,
+/// calls `resolve_ids` on it so stale ids it's holding (e.g. `Connect`'s
+/// `SlotId`s, `MoveNode`'s `NodeId`) get chased through to whatever's
+/// currently live.
+pub trait Command<NodeData, DataType = (), ValueType = ()> {
+    fn apply(&mut self, graph: &mut Graph<NodeData, DataType, ValueType>) -> IdRemap;
+    fn undo(&mut self, graph: &mut Graph<NodeData, DataType, ValueType>) -> IdRemap;
+
+    /// Rewrites any `NodeId`/`SlotId` this command has stashed through the
+    /// given remap tables, chasing multi-step remaps to their end. Called by
+    /// `CommandHistory` immediately before `apply`/`undo`, so a command that
+    /// has been sitting on a stack since before some other command remapped
+    /// its ids still acts on the graph as it exists now. Default no-op: most
+    /// commands don't hold onto ids across an intervening remove/restore.
+    fn resolve_ids(&mut self, _node_remap: &HashMap<NodeId, NodeId>, _slot_remap: &HashMap<SlotId, SlotId>) {}
+
+    /// `Some((node_id, delta))` if this command is (or coalesces) a node
+    /// move, so `CommandHistory` can report the delta back to callers that
+    /// track node positions outside of `Graph` — see `MoveNode`.
+    fn move_delta(&self) -> Option<(NodeId, egui::Vec2)> {
+        None
+    }
+}
+
+/// Adds a node created from the given `label`/`data`. `undo` removes it
+/// again. Unlike `RemoveNode`, `apply` always creates a brand new node, so
+/// there's no stale-id problem across repeated undo/redo: every `apply`
+/// naturally picks up whatever id slotmap hands out that time.
+pub struct AddNode<NodeData> {
+    label: String,
+    data: Option<NodeData>,
+    node_id: Option<NodeId>,
+}
+
+impl<NodeData> AddNode<NodeData> {
+    pub fn new(label: String, data: NodeData) -> Self {
+        Self {
+            label,
+            data: Some(data),
+            node_id: None,
+        }
+    }
+}
+
+impl<NodeData, DataType, ValueType> Command<NodeData, DataType, ValueType> for AddNode<NodeData> {
+    fn apply(&mut self, graph: &mut Graph<NodeData, DataType, ValueType>) -> IdRemap {
+        let data = self.data.take().expect("AddNode applied twice in a row");
+        self.node_id = Some(graph.add_node(self.label.clone(), data));
+        IdRemap::default()
+    }
+
+    fn undo(&mut self, graph: &mut Graph<NodeData, DataType, ValueType>) -> IdRemap {
+        let node_id = self.node_id.take().expect("AddNode undone before apply");
+        let (node, ..) = graph.remove_node(node_id);
+        self.data = Some(node.data);
+        IdRemap::default()
+    }
+}
+
+/// Everything `Graph::remove_node` hands back for one deleted node, kept
+/// around so `RemoveNode::undo` can put it all back exactly as it was.
+struct RemovedNode<NodeData, DataType, ValueType> {
+    node: Node<NodeData>,
+    inputs: Vec<(SlotId, Input<DataType, ValueType>)>,
+    outputs: Vec<(SlotId, Output<DataType>)>,
+    connections: Vec<(SlotId, SlotId)>,
+}
+
+/// Removes a node. Because re-inserting a node via slotmap hands out a brand
+/// new `NodeId`, `undo` has to carry the entire `Node<NodeData>`, its
+/// `Input`/`Output` slot records, and every connection that touched it, and
+/// report the old -> new id so `CommandHistory` can remap any later command
+/// that still references the old id.
+pub struct RemoveNode<NodeData, DataType = (), ValueType = ()> {
+    node_id: NodeId,
+    removed: Option<RemovedNode<NodeData, DataType, ValueType>>,
+}
+
+impl<NodeData, DataType, ValueType> RemoveNode<NodeData, DataType, ValueType> {
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            node_id,
+            removed: None,
+        }
+    }
+}
+
+impl<NodeData, DataType, ValueType> Command<NodeData, DataType, ValueType>
+    for RemoveNode<NodeData, DataType, ValueType>
+{
+    fn apply(&mut self, graph: &mut Graph<NodeData, DataType, ValueType>) -> IdRemap {
+        let (node, inputs, outputs, connections) = graph.remove_node(self.node_id);
+        self.removed = Some(RemovedNode {
+            node,
+            inputs,
+            outputs,
+            connections,
+        });
+        IdRemap::default()
+    }
+
+    /// Re-inserts the node, its slots, and its connections, then updates
+    /// `self.node_id` to the id it was reinserted under — a later `apply`
+    /// (i.e. redo) must remove *that* id, not the one this command was
+    /// originally constructed with. Returns the old -> new remap (node and
+    /// every slot) so `CommandHistory` can fix up anything else still keyed
+    /// by the old ids.
+    ///
+    /// Slotmap hands out fresh keys for the node *and* every slot it owns,
+    /// so this also has to rewrite the node's own `inputs`/`outputs` lists
+    /// and the saved connections to use the new slot ids before restoring
+    /// them — the old ids saved in `self.removed` no longer name anything.
+    fn undo(&mut self, graph: &mut Graph<NodeData, DataType, ValueType>) -> IdRemap {
+        let removed = self.removed.take().expect("RemoveNode undone before apply");
+        let old_id = self.node_id;
+
+        let new_id = graph.add_node_with_id(removed.node);
+
+        let mut slot_remap = HashMap::new();
+        for (old_slot, input) in removed.inputs {
+            slot_remap.insert(old_slot, graph.restore_input(input));
+        }
+        for (old_slot, output) in removed.outputs {
+            slot_remap.insert(old_slot, graph.restore_output(output));
+        }
+
+        for (_, slot) in graph.nodes[new_id]
+            .inputs
+            .iter_mut()
+            .chain(graph.nodes[new_id].outputs.iter_mut())
+        {
+            if let Some(&new_slot) = slot_remap.get(slot) {
+                *slot = new_slot;
+            }
+        }
+
+        for (input, output) in removed.connections {
+            let input = slot_remap.get(&input).copied().unwrap_or(input);
+            let output = slot_remap.get(&output).copied().unwrap_or(output);
+            graph.add_connection(input, output);
+        }
+
+        self.node_id = new_id;
+        IdRemap {
+            node: Some((old_id, new_id)),
+            slots: slot_remap.into_iter().collect(),
+        }
+    }
+}
+
+pub struct Connect {
+    input: SlotId,
+    output: SlotId,
+    previous: Option<SlotId>,
+}
+
+impl Connect {
+    pub fn new(input: SlotId, output: SlotId) -> Self {
+        Self {
+            input,
+            output,
+            previous: None,
+        }
+    }
+}
+
+impl<NodeData, DataType, ValueType> Command<NodeData, DataType, ValueType> for Connect {
+    fn apply(&mut self, graph: &mut Graph<NodeData, DataType, ValueType>) -> IdRemap {
+        self.previous = graph.add_connection(self.input, self.output);
+        IdRemap::default()
+    }
+
+    fn undo(&mut self, graph: &mut Graph<NodeData, DataType, ValueType>) -> IdRemap {
+        graph.remove_connection(self.input);
+        if let Some(previous) = self.previous.take() {
+            graph.add_connection(self.input, previous);
+        }
+        IdRemap::default()
+    }
+
+    /// `self.input`/`self.output` (and `self.previous`, once discovered) name
+    /// slots on a node that may have been removed and restored under fresh
+    /// ids since this command was pushed — chase them through to what's
+    /// actually live before `apply`/`undo` touches the graph.
+    fn resolve_ids(&mut self, _node_remap: &HashMap<NodeId, NodeId>, slot_remap: &HashMap<SlotId, SlotId>) {
+        self.input = follow(slot_remap, self.input);
+        self.output = follow(slot_remap, self.output);
+        if let Some(previous) = self.previous {
+            self.previous = Some(follow(slot_remap, previous));
+        }
+    }
+}
+
+pub struct Disconnect {
+    input: SlotId,
+    output: SlotId,
+}
+
+impl Disconnect {
+    pub fn new(input: SlotId, output: SlotId) -> Self {
+        Self { input, output }
+    }
+}
+
+impl<NodeData, DataType, ValueType> Command<NodeData, DataType, ValueType> for Disconnect {
+    fn apply(&mut self, graph: &mut Graph<NodeData, DataType, ValueType>) -> IdRemap {
+        graph.remove_connection(self.input);
+        IdRemap::default()
+    }
+
+    fn undo(&mut self, graph: &mut Graph<NodeData, DataType, ValueType>) -> IdRemap {
+        graph.add_connection(self.input, self.output);
+        IdRemap::default()
+    }
+
+    /// See `Connect::resolve_ids` — same staleness risk applies here.
+    fn resolve_ids(&mut self, _node_remap: &HashMap<NodeId, NodeId>, slot_remap: &HashMap<SlotId, SlotId>) {
+        self.input = follow(slot_remap, self.input);
+        self.output = follow(slot_remap, self.output);
+    }
+}
+
+/// Moves a node by `delta`. Repeated drags on the same node should be
+/// coalesced into one `MoveNode` via `CommandHistory::coalesce_move`, rather
+/// than pushing one command per dragged frame.
+pub struct MoveNode {
+    pub node_id: NodeId,
+    pub delta: egui::Vec2,
+}
+
+impl MoveNode {
+    pub fn new(node_id: NodeId, delta: egui::Vec2) -> Self {
+        Self { node_id, delta }
+    }
+}
+
+impl<NodeData, DataType, ValueType> Command<NodeData, DataType, ValueType> for MoveNode {
+    // `MoveNode` doesn't touch `graph` directly: node positions live in
+    // `GraphEditor::node_positions`, not in the graph itself. `move_delta`
+    // reports the delta so `CommandHistory::undo`/`redo` can pass it back to
+    // the editor, which applies/undoes it against its own position map.
+    fn apply(&mut self, _graph: &mut Graph<NodeData, DataType, ValueType>) -> IdRemap {
+        IdRemap::default()
+    }
+    fn undo(&mut self, _graph: &mut Graph<NodeData, DataType, ValueType>) -> IdRemap {
+        IdRemap::default()
+    }
+
+    /// `self.node_id` may be stale if the node it targets was removed and
+    /// restored (under a fresh id) since this `MoveNode` was pushed.
+    fn resolve_ids(&mut self, node_remap: &HashMap<NodeId, NodeId>, _slot_remap: &HashMap<SlotId, SlotId>) {
+        self.node_id = follow(node_remap, self.node_id);
+    }
+
+    fn move_delta(&self) -> Option<(NodeId, egui::Vec2)> {
+        Some((self.node_id, self.delta))
+    }
+}
+
+/// Undo stack + redo stack of `Command`s applied to a `Graph<NodeData,
+/// DataType, ValueType>`. The redo stack is cleared whenever a new command
+/// is pushed, matching the usual undo/redo semantics of "redo only replays
+/// history you just undid".
+pub struct CommandHistory<NodeData, DataType = (), ValueType = ()> {
+    undo_stack: Vec<Box<dyn Command<NodeData, DataType, ValueType>>>,
+    redo_stack: Vec<Box<dyn Command<NodeData, DataType, ValueType>>>,
+    /// A `MoveNode` being coalesced: kept off `undo_stack` until the drag
+    /// ends (a different command is pushed, or `end_drag` is called
+    /// explicitly) so repeated drag deltas collapse into one undo step.
+    pending_move: Option<MoveNode>,
+    /// Remaps a `NodeId` that was handed out to a node which has since been
+    /// removed-and-restored (under a new id) back to the id currently live
+    /// in the graph. Consulted by callers that stashed a `NodeId` from an
+    /// older command before resolving it against the graph, and fed into
+    /// `Command::resolve_ids` before any other command on either stack runs.
+    node_id_remap: HashMap<NodeId, NodeId>,
+    /// Same idea as `node_id_remap`, but for the `SlotId`s a restored node's
+    /// inputs/outputs came back under.
+    slot_id_remap: HashMap<SlotId, SlotId>,
+}
+
+impl<NodeData, DataType, ValueType> Default for CommandHistory<NodeData, DataType, ValueType> {
+    fn default() -> Self {
+        Self {
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            pending_move: None,
+            node_id_remap: HashMap::new(),
+            slot_id_remap: HashMap::new(),
+        }
+    }
+}
+
+/// What undoing/redoing a step requires the caller to additionally patch up
+/// outside of `Graph` itself (node-position maps, selection lists, ...).
+#[derive(Default)]
+pub struct HistoryEffect {
+    /// A node came back under a new id; anything keyed by `.0` should move
+    /// to being keyed by `.1`.
+    pub remap: Option<(NodeId, NodeId)>,
+    /// A `MoveNode` step was (un)applied; the caller should add this delta
+    /// to its own position map for `apply`/redo, or subtract it for `undo`.
+    pub move_delta: Option<(NodeId, egui::Vec2)>,
+}
+
+impl<NodeData: 'static, DataType: 'static, ValueType: 'static> CommandHistory<NodeData, DataType, ValueType> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies `command` to `graph` and pushes it onto the undo stack,
+    /// clearing any existing redo history.
+    pub fn push(
+        &mut self,
+        mut command: Box<dyn Command<NodeData, DataType, ValueType>>,
+        graph: &mut Graph<NodeData, DataType, ValueType>,
+    ) -> HistoryEffect {
+        self.flush_pending_move();
+        command.resolve_ids(&self.node_id_remap, &self.slot_id_remap);
+        let remap = command.apply(graph);
+        let effect = self.record_remap(remap, &command);
+        self.undo_stack.push(command);
+        self.redo_stack.clear();
+        effect
+    }
+
+    pub fn undo(&mut self, graph: &mut Graph<NodeData, DataType, ValueType>) -> HistoryEffect {
+        self.flush_pending_move();
+        let Some(mut command) = self.undo_stack.pop() else {
+            return HistoryEffect::default();
+        };
+        command.resolve_ids(&self.node_id_remap, &self.slot_id_remap);
+        let remap = command.undo(graph);
+        let effect = self.record_remap(remap, &command);
+        self.redo_stack.push(command);
+        effect
+    }
+
+    pub fn redo(&mut self, graph: &mut Graph<NodeData, DataType, ValueType>) -> HistoryEffect {
+        self.flush_pending_move();
+        let Some(mut command) = self.redo_stack.pop() else {
+            return HistoryEffect::default();
+        };
+        command.resolve_ids(&self.node_id_remap, &self.slot_id_remap);
+        let remap = command.apply(graph);
+        let effect = self.record_remap(remap, &command);
+        self.undo_stack.push(command);
+        effect
+    }
+
+    /// Folds a just-applied/undone command's `IdRemap` into the history's
+    /// own remap tables (so later commands still sitting on either stack
+    /// resolve through it too) and turns it into the `HistoryEffect` handed
+    /// back to the caller.
+    fn record_remap(
+        &mut self,
+        remap: IdRemap,
+        command: &dyn Command<NodeData, DataType, ValueType>,
+    ) -> HistoryEffect {
+        if let Some((old, new)) = remap.node {
+            self.remap_node_id(old, new);
+        }
+        for (old, new) in remap.slots {
+            self.slot_id_remap.insert(old, new);
+        }
+        HistoryEffect {
+            remap: remap.node,
+            move_delta: command.move_delta(),
+        }
+    }
+
+    /// Records that `old_id` was replaced by `new_id` after a `RemoveNode`
+    /// undo re-inserted the node under a fresh slotmap key. Later lookups of
+    /// `old_id` via `resolve` will return `new_id` instead.
+    pub fn remap_node_id(&mut self, old_id: NodeId, new_id: NodeId) {
+        self.node_id_remap.insert(old_id, new_id);
+    }
+
+    /// Follows the remap table to the id currently live in the graph.
+    pub fn resolve(&self, node_id: NodeId) -> NodeId {
+        follow(&self.node_id_remap, node_id)
+    }
+
+    /// Follows the remap table to the slot id currently live in the graph.
+    pub fn resolve_slot(&self, slot_id: SlotId) -> SlotId {
+        follow(&self.slot_id_remap, slot_id)
+    }
+
+    /// Whether `undo()` has anything to do — used to enable/disable a
+    /// toolbar "Undo" button.
+    pub fn can_undo(&self) -> bool {
+        self.pending_move.is_some() || !self.undo_stack.is_empty()
+    }
+
+    /// Whether `redo()` has anything to do — used to enable/disable a
+    /// toolbar "Redo" button.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Folds `delta` into the in-progress drag for `node_id`, starting a new
+    /// one if none is pending or the node changed. Call `end_drag` once the
+    /// drag gesture finishes to commit it as a single undo step.
+    pub fn coalesce_move(&mut self, node_id: NodeId, delta: egui::Vec2) {
+        match &mut self.pending_move {
+            Some(pending) if pending.node_id == node_id => pending.delta += delta,
+            _ => {
+                self.flush_pending_move();
+                self.pending_move = Some(MoveNode::new(node_id, delta));
+            }
+        }
+    }
+
+    /// Commits any in-progress coalesced `MoveNode` to the undo stack.
+    pub fn end_drag(&mut self) {
+        self.flush_pending_move();
+    }
+
+    fn flush_pending_move(&mut self) {
+        if let Some(pending) = self.pending_move.take() {
+            self.undo_stack.push(Box::new(pending));
+            self.redo_stack.clear();
+        }
+    }
+}
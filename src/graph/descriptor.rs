@@ -0,0 +1,86 @@
+use super::graph::Graph;
+use super::node::NodeId;
+
+/// The kind of value a slot defaults to when nothing feeds it, along with
+/// enough information to build a constant-entry widget for it. `Slider` is
+/// the common case for device axes (joystick/throttle position, a knob) that
+/// map onto a bounded `Float32`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValueKind {
+    Slider { min: f32, max: f32 },
+    Toggle,
+    Text,
+}
+
+/// Describes one input or output slot a `NodeDescriptor` should create.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
+pub struct SlotDescriptor<DataType> {
+    pub name: String,
+    pub data_type: DataType,
+    /// `None` for output slots, which have no inline constant.
+    pub default: Option<ValueKind>,
+}
+
+impl<DataType> SlotDescriptor<DataType> {
+    pub fn input(name: impl Into<String>, data_type: DataType, default: ValueKind) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+            default: Some(default),
+        }
+    }
+
+    pub fn output(name: impl Into<String>, data_type: DataType) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+            default: None,
+        }
+    }
+}
+
+/// A node template built at runtime rather than compiled in — e.g. one slot
+/// per axis/button enumerated off an input device. Turning a `NodeDescriptor`
+/// into an actual node is `Graph::add_node_from_descriptor`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeDescriptor<DataType> {
+    pub label: String,
+    pub inputs: Vec<SlotDescriptor<DataType>>,
+    pub outputs: Vec<SlotDescriptor<DataType>>,
+}
+
+impl<NodeData, DataType, ValueType> Graph<NodeData, DataType, ValueType>
+where
+    DataType: Clone,
+    ValueType: Default,
+{
+    /// Materializes `descriptor` as a real node: adds it via `add_node` with
+    /// `make_data`'s result, then adds one `Input`/`Output` slot per
+    /// `SlotDescriptor`. Inline constants are seeded with `ValueType::default`
+    /// — callers that need the `ValueKind` (e.g. to size a slider) should
+    /// read it back off `descriptor.inputs[i].default`.
+    pub fn add_node_from_descriptor(
+        &mut self,
+        descriptor: &NodeDescriptor<DataType>,
+        make_data: impl FnOnce(&NodeDescriptor<DataType>) -> NodeData,
+    ) -> NodeId {
+        let node_id = self.add_node(descriptor.label.clone(), make_data(descriptor));
+
+        for slot in &descriptor.inputs {
+            self.add_input_param(
+                node_id,
+                slot.name.clone(),
+                slot.data_type.clone(),
+                ValueType::default(),
+            );
+        }
+        for slot in &descriptor.outputs {
+            self.add_output_param(node_id, slot.name.clone(), slot.data_type.clone());
+        }
+
+        node_id
+    }
+}
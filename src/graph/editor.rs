@@ -7,7 +7,6 @@ use egui::*;
 use serde::{Deserialize, Serialize};
 use slotmap::SecondaryMap;
 
-#[derive(Clone)]
 #[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphEditor {
     pub graph: Graph<NodeData, DataType, ValueType>,
@@ -17,6 +16,43 @@ pub struct GraphEditor {
     pub ongoing_box_selection: Option<egui::Pos2>,
     pub node_positions: SecondaryMap<NodeId, egui::Pos2>,
     pub node_finder: Option<NodeFinder<NodeTemplate>>,
+    /// Undo/redo stack. `NodeResponse`s that mutate `graph` are translated
+    /// into `Command`s and pushed here instead of applying the mutation
+    /// directly; see `apply_response`. Not `Clone`/(de)serializable (it's a
+    /// stack of `Box<dyn Command<_>>`), so a cloned or deserialized editor
+    /// starts with empty history; see the manual `Clone` impl below.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub history: CommandHistory<NodeData, DataType, ValueType>,
+    /// Pan/zoom transform for the canvas. Synced with the per-frame copy
+    /// kept in egui memory (see `ViewportState::load`/`store`) so scroll and
+    /// drag input handled while painting nodes is reflected back here.
+    pub pan_zoom: ViewportState,
+    /// Drawn once per frame before `node_order` is iterated. Also not
+    /// `Clone`/(de)serializable for the same reason as `history`; resets to
+    /// `Grid::default()`.
+    #[cfg_attr(feature = "persistence", serde(skip, default = "default_background"))]
+    pub background: Box<dyn BackgroundPattern>,
+}
+
+fn default_background() -> Box<dyn BackgroundPattern> {
+    Box::new(Grid::default())
+}
+
+impl Clone for GraphEditor {
+    fn clone(&self) -> Self {
+        Self {
+            graph: self.graph.clone(),
+            node_order: self.node_order.clone(),
+            connection_in_progress: self.connection_in_progress,
+            selected_nodes: self.selected_nodes.clone(),
+            ongoing_box_selection: self.ongoing_box_selection,
+            node_positions: self.node_positions.clone(),
+            node_finder: self.node_finder.clone(),
+            history: Default::default(),
+            pan_zoom: self.pan_zoom,
+            background: default_background(),
+        }
+    }
 }
 
 impl Default for GraphEditor {
@@ -29,12 +65,121 @@ impl Default for GraphEditor {
             ongoing_box_selection: Default::default(),
             node_positions: Default::default(),
             node_finder: Default::default(),
-            //pan_zoom: Default::default(),
+            history: Default::default(),
+            pan_zoom: Default::default(),
+            background: default_background(),
             //_user_state: Default::default(),
         }
     }
 }
 
+impl GraphEditor {
+    /// Paints `self.background` across `viewport_rect`, using the current
+    /// pan/zoom transform. Callers drawing the canvas should call this
+    /// before iterating `node_order`, so nodes are painted on top.
+    pub fn draw_background(&self, viewport_rect: egui::Rect, painter: &egui::Painter) {
+        self.background
+            .draw(viewport_rect, self.pan_zoom.scale, self.pan_zoom.offset, painter);
+    }
+
+    /// Drives scroll-to-zoom/drag-to-pan for the canvas occupying `rect`,
+    /// syncing the result both into `self.pan_zoom` and into the per-frame
+    /// copy kept in egui memory, as the doc comment on `pan_zoom` promises.
+    /// Call this once per frame, before `draw_background`/iterating
+    /// `node_order`, passing the same `rect` the canvas is painted into.
+    pub fn update_pan_zoom(&mut self, ui: &mut egui::Ui, rect: egui::Rect) -> egui::Response {
+        let id = ui.id().with("pan_zoom");
+        let mut viewport = ViewportState::load(ui.ctx(), id);
+        let response = ui.interact(rect, id, egui::Sense::click_and_drag());
+        viewport.handle_input(ui, rect, &response);
+        viewport.store(ui.ctx(), id);
+        self.pan_zoom = viewport;
+        response
+    }
+
+    /// Translates a `NodeResponse` that mutates the graph into a `Command`
+    /// and pushes it onto `history`, so it can later be undone/redone.
+    /// Responses that don't mutate the graph (selection, hover, the
+    /// "connection started" preview) are left for the caller to handle.
+    pub fn apply_response(&mut self, response: &NodeResponse) {
+        match *response {
+            NodeResponse::ConnectEventEnded { input, output } => {
+                self.history
+                    .push(Box::new(Connect::new(input, output)), &mut self.graph);
+            }
+            NodeResponse::DisconnectEvent { input, output } => {
+                self.history
+                    .push(Box::new(Disconnect::new(input, output)), &mut self.graph);
+            }
+            NodeResponse::DeleteNodeFull { node_id } => {
+                self.history
+                    .push(Box::new(RemoveNode::new(node_id)), &mut self.graph);
+            }
+            NodeResponse::MoveNode { node, drag_delta } => {
+                self.history.coalesce_move(node, drag_delta);
+                if let Some(position) = self.node_positions.get_mut(node) {
+                    *position += drag_delta;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Draws a row of "Undo"/"Redo" buttons wired up to `self.undo`/
+    /// `self.redo`, disabled when there's nothing to undo/redo. Callers
+    /// typically place this in a top panel above the canvas.
+    pub fn toolbar_ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(self.history.can_undo(), egui::Button::new("Undo"))
+                .clicked()
+            {
+                self.undo();
+            }
+            if ui
+                .add_enabled(self.history.can_redo(), egui::Button::new("Redo"))
+                .clicked()
+            {
+                self.redo();
+            }
+        });
+    }
+
+    pub fn undo(&mut self) {
+        let effect = self.history.undo(&mut self.graph);
+        self.apply_history_effect(effect, -1.0);
+    }
+
+    pub fn redo(&mut self) {
+        let effect = self.history.redo(&mut self.graph);
+        self.apply_history_effect(effect, 1.0);
+    }
+
+    /// Patches `node_positions`/`selected_nodes` for whatever `history.undo`
+    /// or `history.redo` just did outside of `graph` itself: a node that
+    /// came back under a new id (`effect.remap`), or a `MoveNode` step
+    /// (`effect.move_delta`, applied forwards on redo and backwards on
+    /// undo via `direction`).
+    fn apply_history_effect(&mut self, effect: HistoryEffect, direction: f32) {
+        if let Some((old_id, new_id)) = effect.remap {
+            if let Some(position) = self.node_positions.remove(old_id) {
+                self.node_positions.insert(new_id, position);
+            }
+            for selected in self.selected_nodes.iter_mut() {
+                if *selected == old_id {
+                    *selected = new_id;
+                }
+            }
+        }
+
+        if let Some((node_id, delta)) = effect.move_delta {
+            if let Some(position) = self.node_positions.get_mut(node_id) {
+                *position += delta * direction;
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 #[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
 pub struct NodeFinder<NodeTemplate> {
@@ -42,9 +187,32 @@ pub struct NodeFinder<NodeTemplate> {
     /// Reset every frame. When set, the node finder will be moved at that position
     pub position: Option<egui::Pos2>,
     pub just_spawned: bool,
+    /// Templates registered at runtime (e.g. one per discovered input-device
+    /// axis/button) shown in search results alongside the static
+    /// `NodeTemplate`s, rather than requiring every spawnable node to be a
+    /// compiled-in variant.
+    pub dynamic_templates: Vec<NodeDescriptor<DataType>>,
     //_phantom: PhantomData<NodeTemplate>,
 }
 
+impl<NodeTemplate> NodeFinder<NodeTemplate> {
+    /// Registers `descriptor` by appending it to `dynamic_templates`. It is
+    /// kept by `Clone` and, with the `persistence` feature, serialized along
+    /// with the `NodeFinder`.
+    pub fn register_descriptor(&mut self, descriptor: NodeDescriptor<DataType>) {
+        self.dynamic_templates.push(descriptor);
+    }
+
+    /// Dynamic templates whose label contains the current query, compared
+    /// case insensitively.
+    pub fn matching_dynamic_templates(&self) -> impl Iterator<Item = &NodeDescriptor<DataType>> {
+        let query = self.query.to_lowercase();
+        self.dynamic_templates
+            .iter()
+            .filter(move |descriptor| descriptor.label.to_lowercase().contains(&query))
+    }
+}
+
 /// Nodes communicate certain events to the parent graph when drawn. There is
 /// one special `User` variant which can be used by users as the return value
 /// when executing some custom actions in the UI of the node.
@@ -79,6 +247,110 @@ pub enum NodeResponse {
     },
 }
 
+/// Every node/port rect for the current frame, computed up front by
+/// `register_hitboxes` before anything is painted. Resolving hover/topmost
+/// against this (instead of against rects stashed in memory from the
+/// *previous* frame, or rects still being computed mid-paint) is what
+/// removes the one-frame-lagged flicker you'd otherwise get when nodes
+/// overlap or a node resizes.
+#[derive(Default)]
+pub struct Hitboxes {
+    pub node_rects: std::collections::HashMap<NodeId, egui::Rect>,
+    pub port_rects: std::collections::HashMap<SlotId, egui::Rect>,
+}
+
+impl Hitboxes {
+    /// The frontmost node under `pointer`, i.e. the last one in `node_order`
+    /// whose rect contains it (later entries are drawn on top).
+    pub fn topmost_node_at(&self, pointer: egui::Pos2, node_order: &[NodeId]) -> Option<NodeId> {
+        node_order
+            .iter()
+            .rev()
+            .copied()
+            .find(|id| matches!(self.node_rects.get(id), Some(rect) if rect.contains(pointer)))
+    }
+
+    /// The port closest to `pointer`, if any is within `distance` — used for
+    /// "close enough to connect" hit-testing.
+    pub fn port_at(&self, pointer: egui::Pos2, distance: f32) -> Option<SlotId> {
+        self.port_rects
+            .iter()
+            .filter(|(_, rect)| rect.center().distance(pointer) < distance)
+            .min_by(|(_, a), (_, b)| {
+                a.center()
+                    .distance(pointer)
+                    .total_cmp(&b.center().distance(pointer))
+            })
+            .map(|(id, _)| *id)
+    }
+}
+
+/// First phase of the two-phase layout: computes every node's (and, as an
+/// upper-bound placeholder, every port's) screen-space rect from
+/// `node_positions` and the current viewport transform, without painting
+/// anything. Call this once per frame before `NodeWidget::show`, and resolve
+/// hover/selection against the result rather than against `show`'s own
+/// same-frame or previous-frame geometry.
+pub fn register_hitboxes<NodeData, DataType, ValueType>(
+    graph: &Graph<NodeData, DataType, ValueType>,
+    node_order: &[NodeId],
+    node_positions: &SecondaryMap<NodeId, egui::Pos2>,
+    viewport: ViewportState,
+) -> Hitboxes {
+    let mut hitboxes = Hitboxes::default();
+
+    for &node_id in node_order {
+        let Some(&position) = node_positions.get(node_id) else {
+            continue;
+        };
+        let rect = egui::Rect::from_min_size(
+            viewport.to_screen(position),
+            egui::Vec2::from(NodeWidget::MAX_NODE_SIZE) * viewport.scale,
+        );
+        hitboxes.node_rects.insert(node_id, rect);
+
+        let Some(node) = graph.nodes.get(node_id) else {
+            continue;
+        };
+        // Exact per-port height depends on each field's rendered size, which
+        // isn't known until `paint` lays the node out; until then, the
+        // node's own rect is a conservative stand-in so hover-testing a
+        // port never needs last frame's numbers.
+        for (_, slot) in node.inputs.iter().chain(node.outputs.iter()) {
+            hitboxes.port_rects.insert(*slot, rect);
+        }
+    }
+
+    hitboxes
+}
+
+/// Paints every live connection in `graph` as a wire between its two ports,
+/// picking a `WireStyle` per connection via `wire_style_for` so connections
+/// read apart at a glance by type, not just by each port's color. Reads
+/// port positions from `port_locations` — the same map `draw_port` fills in
+/// while painting ports — so call this only after every node in
+/// `node_order` has already been drawn this frame.
+pub fn draw_connections<NodeData, DataType, ValueType, UserState>(
+    graph: &Graph<NodeData, DataType, ValueType>,
+    port_locations: &std::collections::HashMap<SlotId, egui::Pos2>,
+    painter: &egui::Painter,
+    user_state: &UserState,
+) where
+    DataType: DataTypeTrait<UserState> + PartialEq,
+{
+    for (input, &output) in graph.connections.iter() {
+        let (Some(&dst), Some(&src)) = (port_locations.get(&input), port_locations.get(&output)) else {
+            continue;
+        };
+
+        let input_type = &graph.inputs[input].typ;
+        let output_type = &graph.outputs[output].typ;
+        let style = wire_style_for(output_type, input_type);
+        let stroke = egui::Stroke::new(2.0, output_type.data_type_color(user_state));
+        draw_connection(painter, src, dst, style, stroke);
+    }
+}
+
 pub struct NodeWidget<'a> {
     pub position: egui::Pos2,
     pub graph: &'a mut Graph<NodeData, DataType, ValueType>,
@@ -87,7 +359,14 @@ pub struct NodeWidget<'a> {
     pub node_id: NodeId,
     pub ongoing_drag: Option<(NodeId, SlotId)>,
     pub selected: bool,
-    pub pan: egui::Vec2,
+    /// Pan/zoom transform for the canvas this node is drawn on. Replaces the
+    /// old plain `pan: Vec2` offset now that the editor supports zooming.
+    pub viewport: ViewportState,
+    /// This frame's node/port rects, computed by `register_hitboxes` before
+    /// any node was painted. `show`/`draw_port` resolve hover and interaction
+    /// hit-testing against this instead of memory left over from the
+    /// previous frame, which is what produced the one-frame flicker.
+    pub hitboxes: &'a Hitboxes,
 }
 
 impl<'a> NodeWidget<'a> {
@@ -102,19 +381,27 @@ impl<'a> NodeWidget<'a> {
     ) -> Vec<NodeResponse<UserResponse, NodeData>> {
         use egui::*;
 
+        let scale = self.viewport.scale;
+
         let mut ui = ui.child_ui_with_id_source(
-            Rect::from_min_size(*self.position + self.pan, Self::MAX_NODE_SIZE.into()),
+            Rect::from_min_size(
+                self.viewport.to_screen(*self.position),
+                (Vec2::from(Self::MAX_NODE_SIZE) * scale).into(),
+            ),
             Layout::default(),
             self.node_id,
         );
 
-        let margin = egui::vec2(15.0, 5.0);
+        let margin = egui::vec2(15.0, 5.0) * scale;
         let mut responses = Vec::<NodeResponse<UserResponse, NodeData>>::new();
 
         let background_color = ui.visuals().widgets.inactive.bg_fill;
         let text_color = ui.visuals().widgets.inactive.text_color();
 
-        ui.visuals_mut().widgets.noninteractive.fg_stroke = Stroke::new(2.0, text_color);
+        ui.visuals_mut().widgets.noninteractive.fg_stroke = Stroke::new(2.0 * scale, text_color);
+        for (_, font_id) in ui.style_mut().text_styles.iter_mut() {
+            font_id.size *= scale;
+        }
 
         // Preallocate shapes to paint below contents
         let outline_shape = ui.painter().add(Shape::Noop);
@@ -130,14 +417,15 @@ impl<'a> NodeWidget<'a> {
 
         let mut child_ui = ui.child_ui(inner_rect, *ui.layout());
 
-        // Get interaction rect from memory, it may expand after the window response on resize.
-        let interaction_rect = ui
-            .ctx()
-            .memory_mut(|mem| {
-                mem.data
-                    .get_temp::<Rect>(child_ui.id())
-                    .map(|stored| stored.0)
-            })
+        // Interaction rect: use this frame's pre-computed hitbox (from
+        // `register_hitboxes`) rather than last frame's rect stashed in
+        // memory, so a node that resized this frame doesn't hit-test against
+        // stale geometry for one frame.
+        let interaction_rect = self
+            .hitboxes
+            .node_rects
+            .get(&self.node_id)
+            .copied()
             .unwrap_or(outer_rect_bounds);
         // After 0.20, layers added over others can block hover interaction. Call this first
         // before creating the node content.
@@ -287,6 +575,8 @@ impl<'a> NodeWidget<'a> {
                     self.port_locations,
                     self.ongoing_drag,
                     self.graph.connection(*param).is_some(),
+                    scale,
+                    self.hitboxes,
                 );
             }
         }
@@ -309,6 +599,8 @@ impl<'a> NodeWidget<'a> {
                 self.port_locations,
                 self.ongoing_drag,
                 false,
+                scale,
+                self.hitboxes,
             );
         }
 
@@ -317,7 +609,7 @@ impl<'a> NodeWidget<'a> {
         // does not support drawing rectangles with asymmetrical round corners.
 
         let (shape, outline) = {
-            let rounding_radius = 4.0;
+            let rounding_radius = 4.0 * scale;
             let rounding = Rounding::same(rounding_radius);
 
             let titlebar_height = title_height + margin.y;
@@ -364,7 +656,7 @@ impl<'a> NodeWidget<'a> {
             let node_rect = titlebar_rect.union(body_rect).union(bottom_body_rect);
             let outline = if self.selected {
                 Shape::Rect(RectShape {
-                    rect: node_rect.expand(1.0),
+                    rect: node_rect.expand(1.0 * scale),
                     rounding,
                     fill: Color32::WHITE.lighten(0.8),
                     stroke: Stroke::NONE,
@@ -393,12 +685,14 @@ impl<'a> NodeWidget<'a> {
             user_state,
         );
 
-        if can_delete && Self::close_button(ui, outer_rect).clicked() {
+        if can_delete && Self::close_button(ui, outer_rect, scale).clicked() {
             responses.push(NodeResponse::DeleteNodeUi(self.node_id));
         };
 
-        // Movement
-        let drag_delta = window_response.drag_delta();
+        // Movement. `window_response` reports the drag in screen space; the
+        // node's own position is tracked in world space, so undo it by the
+        // current zoom before reporting the delta.
+        let drag_delta = window_response.drag_delta() / scale;
         if drag_delta.length_sq() > 0.0 {
             responses.push(NodeResponse::MoveNode {
                 node: self.node_id,
@@ -419,11 +713,11 @@ impl<'a> NodeWidget<'a> {
         responses
     }
 
-    fn close_button(ui: &mut Ui, node_rect: Rect) -> Response {
+    fn close_button(ui: &mut Ui, node_rect: Rect, scale: f32) -> Response {
         // Measurements
-        let margin = 8.0;
-        let size = 10.0;
-        let stroke_width = 2.0;
+        let margin = 8.0 * scale;
+        let size = 10.0 * scale;
+        let stroke_width = 2.0 * scale;
         let offs = margin + size / 2.0;
 
         let position = pos2(node_rect.right() - offs, node_rect.top() + offs);
@@ -457,6 +751,8 @@ impl<'a> NodeWidget<'a> {
         port_locations: &mut std::collections::HashMap<SlotId, Pos2>,
         ongoing_drag: Option<(NodeId, SlotId)>,
         is_connected_input: bool,
+        scale: f32,
+        hitboxes: &Hitboxes,
     ) where
         DataType: DataTypeTrait<UserState>,
         UserResponse: UserResponseTrait,
@@ -466,7 +762,7 @@ impl<'a> NodeWidget<'a> {
 
         let port_type = graph.any_param_type(param_id).unwrap();
 
-        let port_rect = Rect::from_center_size(port_pos, egui::vec2(10.0, 10.0));
+        let port_rect = Rect::from_center_size(port_pos, egui::vec2(10.0, 10.0) * scale);
 
         let sense = if ongoing_drag.is_some() {
             Sense::hover()
@@ -476,20 +772,22 @@ impl<'a> NodeWidget<'a> {
 
         let resp = ui.allocate_rect(port_rect, sense);
 
-        // Check if the distance between the port and the mouse is the distance to connect
-        let close_enough = if let Some(pointer_pos) = ui.ctx().pointer_hover_pos() {
-            port_rect.center().distance(pointer_pos) < DISTANCE_TO_CONNECT
-        } else {
-            false
-        };
+        // Closest port to the pointer, resolved against this frame's
+        // pre-computed hitboxes rather than this port's own just-painted
+        // rect, so which port is "close enough" doesn't flicker between the
+        // hovered port and its neighbor as nodes repaint.
+        let close_enough = ui
+            .ctx()
+            .pointer_hover_pos()
+            .and_then(|pointer| hitboxes.port_at(pointer, DISTANCE_TO_CONNECT * scale))
+            == Some(param_id);
 
-        let port_color = if close_enough {
-            Color32::WHITE
+        let pin_info = if close_enough {
+            PinInfo::circle(Color32::WHITE)
         } else {
-            port_type.data_type_color(user_state)
+            port_type.pin_info(user_state)
         };
-        ui.painter()
-            .circle(port_rect.center(), 5.0, port_color, Stroke::NONE);
+        pin_info.paint(ui.painter(), port_rect.center(), 5.0 * scale);
 
         if resp.drag_started() {
             if is_connected_input {
@@ -0,0 +1,184 @@
+use std::collections::{HashMap, HashSet};
+
+use slotmap::SecondaryMap;
+
+use super::graph::Graph;
+use super::node::{NodeId, SlotId};
+use super::value::Value;
+
+/// Supplies the per-node computation the evaluator drives. Implemented by
+/// whatever `NodeData` type an application plugs into `Graph<NodeData>`.
+pub trait NodeDataTrait {
+    /// Computes this node's outputs (in the same order as `Node::outputs`)
+    /// from its resolved inputs (in the same order as `Node::inputs`).
+    fn evaluate(&self, inputs: &[Value]) -> Vec<Value>;
+
+    /// The inline constant to use for the input at `local_index` (its
+    /// position in `Node::inputs`) when nothing is connected to it.
+    fn input_constant(&self, local_index: usize) -> Value;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    /// Evaluating `node` would require re-entering a node still being
+    /// evaluated further up the call stack.
+    Cycle(NodeId),
+    /// The output feeding `input_slot` on `node` produced a `Matrix` (or a
+    /// scalar) where the connection's expected data type didn't allow it.
+    TypeMismatch {
+        node: NodeId,
+        input_slot: SlotId,
+        expected: &'static str,
+        got: &'static str,
+    },
+}
+
+/// Evaluates a `Graph<NodeData>` by depth-first post-order traversal:
+/// to compute a node's outputs, first resolve every input (recursing into
+/// whatever feeds it), then call `NodeDataTrait::evaluate`. Outputs are
+/// memoized per evaluation run, so a value shared by multiple downstream
+/// nodes (a diamond in the dataflow) is computed once.
+pub struct Evaluator<'g, NodeData> {
+    graph: &'g Graph<NodeData>,
+    /// Maps every input/output `SlotId` back to the node that owns it,
+    /// built once up front since `Graph` doesn't track this itself.
+    slot_owner: HashMap<SlotId, NodeId>,
+    /// Resolved output values, keyed by output slot, for this run.
+    cache: SecondaryMap<SlotId, Value>,
+    /// Nodes whose `evaluate` has already run this pass. Tracked separately
+    /// from `cache` because a node with zero outputs (a side-effecting sink)
+    /// would otherwise look "already done" before it ever runs — `cache`
+    /// only ever gains entries for nodes that *have* outputs.
+    evaluated: HashSet<NodeId>,
+    /// Nodes currently being evaluated further up the call stack; used to
+    /// detect cycles instead of overflowing the stack.
+    visiting: HashSet<NodeId>,
+}
+
+impl<'g, NodeData: NodeDataTrait> Evaluator<'g, NodeData> {
+    pub fn new(graph: &'g Graph<NodeData>) -> Self {
+        let mut slot_owner = HashMap::new();
+        for (node_id, node) in graph.nodes.iter() {
+            for (_, slot) in node.inputs.iter().chain(node.outputs.iter()) {
+                slot_owner.insert(*slot, node_id);
+            }
+        }
+
+        Self {
+            graph,
+            slot_owner,
+            cache: SecondaryMap::new(),
+            evaluated: HashSet::new(),
+            visiting: HashSet::new(),
+        }
+    }
+
+    /// Evaluates `target` and returns its output values, in `Node::outputs`
+    /// order.
+    pub fn evaluate(&mut self, target: NodeId) -> Result<Vec<Value>, EvalError> {
+        self.evaluate_node(target)?;
+        let node = &self.graph.nodes[target];
+        Ok(node
+            .outputs
+            .iter()
+            .map(|(_, slot)| self.cache[*slot].clone())
+            .collect())
+    }
+
+    fn evaluate_node(&mut self, node_id: NodeId) -> Result<(), EvalError> {
+        if self.evaluated.contains(&node_id) {
+            return Ok(());
+        }
+
+        if !self.visiting.insert(node_id) {
+            return Err(EvalError::Cycle(node_id));
+        }
+
+        // `visiting.remove` must run on every exit path, not just the happy
+        // path: the recursive `self.evaluate_node(owner)?` below can
+        // propagate a `Cycle`/`TypeMismatch` error from a deeper call via
+        // `?`, which would otherwise skip the cleanup and leak `node_id`
+        // into `visiting` for the rest of this `Evaluator`'s lifetime,
+        // causing spurious `Cycle` errors on a later, unrelated `evaluate()`
+        // call that happens to revisit this node.
+        let result = self.evaluate_node_inner(node_id);
+        self.visiting.remove(&node_id);
+        result?;
+        self.evaluated.insert(node_id);
+        Ok(())
+    }
+
+    fn evaluate_node_inner(&mut self, node_id: NodeId) -> Result<(), EvalError> {
+        let node = &self.graph.nodes[node_id];
+        let inputs = node.inputs.clone();
+        let mut resolved = Vec::with_capacity(inputs.len());
+        for (local_index, (_, input_slot)) in inputs.iter().enumerate() {
+            let value = match self.graph.connection(*input_slot) {
+                Some(output_slot) => {
+                    let owner = *self
+                        .slot_owner
+                        .get(&output_slot)
+                        .expect("connected output slot should belong to some node");
+                    self.evaluate_node(owner)?;
+                    let value = self.cache[output_slot].clone();
+
+                    let expected = self.graph.nodes[node_id]
+                        .data
+                        .input_constant(local_index)
+                        .type_name();
+                    let got = value.type_name();
+                    if got != expected {
+                        return Err(EvalError::TypeMismatch {
+                            node: node_id,
+                            input_slot: *input_slot,
+                            expected,
+                            got,
+                        });
+                    }
+
+                    value
+                }
+                None => self.graph.nodes[node_id].data.input_constant(local_index),
+            };
+            resolved.push(value);
+        }
+
+        let outputs = self.graph.nodes[node_id].data.evaluate(&resolved);
+        let node = &self.graph.nodes[node_id];
+        for ((_, slot), value) in node.outputs.iter().zip(outputs.into_iter()) {
+            self.cache.insert(*slot, value);
+        }
+
+        Ok(())
+    }
+}
+
+/// Elementwise-applies `op` to `a` and `b`, widening either side to a matrix
+/// (via `Value::as_matrix`). Mirrors ndarray's broadcasting for the one
+/// shape combination that actually comes up here: a scalar (a 1x1 matrix)
+/// against an NxM one. Mismatched non-scalar shapes are a hard error rather
+/// than a silent reshape.
+pub fn broadcast_binary(
+    a: &Value,
+    b: &Value,
+    op: impl Fn(f32, f32) -> f32,
+) -> Result<ndarray::Array2<f32>, String> {
+    let a = a.as_matrix();
+    let b = b.as_matrix();
+
+    if a.dim() == b.dim() {
+        Ok(ndarray::Zip::from(&a).and(&b).map_collect(|x, y| op(*x, *y)))
+    } else if a.dim() == (1, 1) {
+        let scalar = a[(0, 0)];
+        Ok(b.map(|y| op(scalar, *y)))
+    } else if b.dim() == (1, 1) {
+        let scalar = b[(0, 0)];
+        Ok(a.map(|x| op(x, scalar)))
+    } else {
+        Err(format!(
+            "cannot broadcast Matrix shapes {:?} and {:?}",
+            a.dim(),
+            b.dim()
+        ))
+    }
+}
@@ -3,17 +3,24 @@ use slotmap::SlotMap;
 
 use super::node::*;
 
-pub struct Graph<NodeData> {
+#[derive(Clone, Default)]
+pub struct Graph<NodeData, DataType = (), ValueType = ()> {
     pub nodes: SlotMap<NodeId, Node<NodeData>>,
-    // pub inputs: SlotMap<InputId, InputParam<DataType, ValueType>>,
-    // pub outputs: SlotMap<OutputId, OutputParam<DataType>>,
-    pub connections: SecondaryMap<InputId, OutputId>,
+    pub inputs: SlotMap<SlotId, Input<DataType, ValueType>>,
+    pub outputs: SlotMap<SlotId, Output<DataType>>,
+    /// Maps an input slot to the output slot it's wired to. Both sides share
+    /// the `SlotId` key space (there's no separate `InputId`/`OutputId` type
+    /// yet), so this map only ever contains slots that are actually inputs
+    /// as keys and actually outputs as values.
+    pub connections: SecondaryMap<SlotId, SlotId>,
 }
 
-impl<NodeData> Graph<NodeData> {
+impl<NodeData, DataType, ValueType> Graph<NodeData, DataType, ValueType> {
     pub fn new() -> Self {
         Self {
             nodes: SlotMap::default(),
+            inputs: SlotMap::default(),
+            outputs: SlotMap::default(),
             connections: SecondaryMap::default(),
         }
     }
@@ -33,4 +40,117 @@ impl<NodeData> Graph<NodeData> {
         });
         node_id
     }
+
+    pub fn add_input_param(
+        &mut self,
+        node_id: NodeId,
+        name: String,
+        typ: DataType,
+        value: ValueType,
+    ) -> SlotId {
+        let slot = self.inputs.insert_with_key(|id| Input {
+            id,
+            typ,
+            value,
+            node: node_id,
+        });
+        self.nodes[node_id].inputs.push((name, slot));
+        slot
+    }
+
+    pub fn add_output_param(&mut self, node_id: NodeId, name: String, typ: DataType) -> SlotId {
+        let slot = self.outputs.insert_with_key(|id| Output {
+            id,
+            node: node_id,
+            typ,
+        });
+        self.nodes[node_id].outputs.push((name, slot));
+        slot
+    }
+
+    /// Re-inserts a previously removed node. Slotmap always hands out a
+    /// fresh key on insertion (never the one the node held before), so this
+    /// returns that new `NodeId` — callers restoring a node from a saved
+    /// `Command` must use the returned id in place of the old one from here
+    /// on, including when fixing up the node's own `inputs`/`outputs` slot
+    /// lists and any connections that referenced it.
+    pub fn add_node_with_id(&mut self, mut node: Node<NodeData>) -> NodeId {
+        self.nodes.insert_with_key(|id| {
+            node.id = id;
+            node
+        })
+    }
+
+    /// Re-inserts a previously removed input slot. Like `add_node_with_id`,
+    /// slotmap hands out a fresh key, so this returns it; callers must use
+    /// the returned id in place of `input.id` when restoring connections and
+    /// the owning node's `inputs` list.
+    pub fn restore_input(&mut self, input: Input<DataType, ValueType>) -> SlotId {
+        self.inputs.insert_with_key(|id| Input { id, ..input })
+    }
+
+    /// Re-inserts a previously removed output slot. See `restore_input`.
+    pub fn restore_output(&mut self, output: Output<DataType>) -> SlotId {
+        self.outputs.insert_with_key(|id| Output { id, ..output })
+    }
+
+    /// Removes a node, every `Input`/`Output` it owns, and every connection
+    /// touching it (as either endpoint), returning all of it so the caller
+    /// can restore it later.
+    #[allow(clippy::type_complexity)]
+    pub fn remove_node(
+        &mut self,
+        node_id: NodeId,
+    ) -> (
+        Node<NodeData>,
+        Vec<(SlotId, Input<DataType, ValueType>)>,
+        Vec<(SlotId, Output<DataType>)>,
+        Vec<(SlotId, SlotId)>,
+    ) {
+        let removed = self.nodes.remove(node_id).expect("node_id should be valid");
+
+        let slots: std::collections::HashSet<SlotId> = removed
+            .inputs
+            .iter()
+            .chain(removed.outputs.iter())
+            .map(|(_, slot)| *slot)
+            .collect();
+
+        let mut removed_connections = Vec::new();
+        self.connections.retain(|input, output| {
+            if slots.contains(&input) || slots.contains(&*output) {
+                removed_connections.push((input, *output));
+                false
+            } else {
+                true
+            }
+        });
+
+        let removed_inputs = removed
+            .inputs
+            .iter()
+            .filter_map(|(_, slot)| self.inputs.remove(*slot).map(|input| (*slot, input)))
+            .collect();
+        let removed_outputs = removed
+            .outputs
+            .iter()
+            .filter_map(|(_, slot)| self.outputs.remove(*slot).map(|output| (*slot, output)))
+            .collect();
+
+        (removed, removed_inputs, removed_outputs, removed_connections)
+    }
+
+    /// Connects `input` to `output`, returning whatever `input` was
+    /// previously wired to (if anything), so callers can restore it on undo.
+    pub fn add_connection(&mut self, input: SlotId, output: SlotId) -> Option<SlotId> {
+        self.connections.insert(input, output)
+    }
+
+    pub fn remove_connection(&mut self, input: SlotId) -> Option<SlotId> {
+        self.connections.remove(input)
+    }
+
+    pub fn connection(&self, input: SlotId) -> Option<SlotId> {
+        self.connections.get(input).copied()
+    }
 }
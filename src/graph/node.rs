@@ -1,6 +1,7 @@
 slotmap::new_key_type! { pub struct NodeId; }
 slotmap::new_key_type! { pub struct SlotId; }
 
+#[derive(Clone)]
 pub struct Node<T> {
     pub id: NodeId,
     pub label: String,
@@ -0,0 +1,142 @@
+use eframe::egui;
+use egui::epaint::CubicBezierShape;
+use egui::{Color32, Pos2, Shape, Stroke};
+
+/// The glyph a port is drawn as. Lets a `DataType` give its ports a distinct
+/// silhouette (not just a color) so e.g. `Int32`/`Float32`/`Matrix` read
+/// apart at a glance even for colorblind users.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PinShape {
+    Circle,
+    Triangle,
+    Square,
+    Star,
+}
+
+impl Default for PinShape {
+    fn default() -> Self {
+        PinShape::Circle
+    }
+}
+
+/// Everything `draw_port` needs to paint a port: its glyph plus fill/stroke.
+#[derive(Clone, Copy, Debug)]
+pub struct PinInfo {
+    pub shape: PinShape,
+    pub fill: Color32,
+    pub stroke: Stroke,
+}
+
+impl PinInfo {
+    pub fn circle(fill: Color32) -> Self {
+        Self {
+            shape: PinShape::Circle,
+            fill,
+            stroke: Stroke::NONE,
+        }
+    }
+
+    /// Paints this pin, centered at `center`, at the given on-screen radius.
+    pub fn paint(&self, painter: &egui::Painter, center: Pos2, radius: f32) {
+        match self.shape {
+            PinShape::Circle => {
+                painter.circle(center, radius, self.fill, self.stroke);
+            }
+            PinShape::Square => {
+                let rect = egui::Rect::from_center_size(center, egui::vec2(radius, radius) * 2.0);
+                painter.rect(rect, egui::Rounding::none(), self.fill, self.stroke);
+            }
+            PinShape::Triangle => {
+                let points = vec![
+                    center + egui::vec2(0.0, -radius),
+                    center + egui::vec2(radius * 0.866, radius * 0.5),
+                    center + egui::vec2(-radius * 0.866, radius * 0.5),
+                ];
+                painter.add(Shape::convex_polygon(points, self.fill, self.stroke));
+            }
+            PinShape::Star => {
+                let mut points = Vec::with_capacity(10);
+                for i in 0..10 {
+                    let angle = std::f32::consts::FRAC_PI_5 * i as f32 - std::f32::consts::FRAC_PI_2;
+                    let r = if i % 2 == 0 { radius } else { radius * 0.45 };
+                    points.push(center + r * egui::vec2(angle.cos(), angle.sin()));
+                }
+                painter.add(Shape::convex_polygon(points, self.fill, self.stroke));
+            }
+        }
+    }
+}
+
+/// How a connection's wire is routed between two ports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WireStyle {
+    /// Smooth cubic bezier, control points offset horizontally from the
+    /// endpoints. The default, good-looking-everywhere choice.
+    Bezier,
+    /// Right-angle segments (a single horizontal midpoint step), useful for
+    /// schematic/logic-style graphs.
+    AxisAligned,
+    /// A plain straight line.
+    Linear,
+}
+
+/// A `DataType` picks its own pin glyph and color, and may be trait-queried
+/// on both ends of a connection to choose how its wire is drawn.
+pub trait DataTypeTrait<UserState> {
+    fn data_type_color(&self, user_state: &UserState) -> Color32;
+
+    /// Defaults to a plain circle in `data_type_color`; override to give a
+    /// type its own silhouette.
+    fn pin_info(&self, user_state: &UserState) -> PinInfo {
+        PinInfo::circle(self.data_type_color(user_state))
+    }
+
+    /// The wire style to use when this data type is the *output* end of a
+    /// connection. Defaults to `Bezier`.
+    fn wire_style(&self) -> WireStyle {
+        WireStyle::Bezier
+    }
+}
+
+/// Picks the wire style for a connection given the data types of its two
+/// endpoints. Matching types defer to the type's own preference; mismatched
+/// or otherwise untyped links fall back to a neutral `Bezier`, since there's
+/// no single type to ask.
+pub fn wire_style_for<UserState, DataType: DataTypeTrait<UserState> + PartialEq>(
+    output_type: &DataType,
+    input_type: &DataType,
+) -> WireStyle {
+    if output_type == input_type {
+        output_type.wire_style()
+    } else {
+        WireStyle::Bezier
+    }
+}
+
+/// Paints a connection from `src` (output port) to `dst` (input port) using
+/// the given `style`.
+pub fn draw_connection(painter: &egui::Painter, src: Pos2, dst: Pos2, style: WireStyle, stroke: Stroke) {
+    match style {
+        WireStyle::Linear => {
+            painter.line_segment([src, dst], stroke);
+        }
+        WireStyle::AxisAligned => {
+            let mid_x = (src.x + dst.x) / 2.0;
+            painter.line_segment([src, Pos2::new(mid_x, src.y)], stroke);
+            painter.line_segment([Pos2::new(mid_x, src.y), Pos2::new(mid_x, dst.y)], stroke);
+            painter.line_segment([Pos2::new(mid_x, dst.y), dst], stroke);
+        }
+        WireStyle::Bezier => {
+            let distance = (dst.x - src.x).abs().max(30.0);
+            let control_scale = distance * 0.5;
+            let src_control = src + egui::vec2(control_scale, 0.0);
+            let dst_control = dst - egui::vec2(control_scale, 0.0);
+            painter.add(Shape::CubicBezier(CubicBezierShape::from_points_stroke(
+                [src, src_control, dst_control, dst],
+                false,
+                Color32::TRANSPARENT,
+                stroke,
+            )));
+        }
+    }
+}
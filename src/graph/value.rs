@@ -1,7 +1,28 @@
 use ndarray::prelude::*;
 
-enum Value {
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
     Int32(i32),
     Float32(f32),
     Matrix(Array2<f32>),
 }
+
+impl Value {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Int32(_) => "Int32",
+            Value::Float32(_) => "Float32",
+            Value::Matrix(_) => "Matrix",
+        }
+    }
+
+    /// Widens a scalar to a 1x1 matrix so it can be mixed with a `Matrix` via
+    /// ndarray's broadcasting rules; `Matrix` values pass through unchanged.
+    pub fn as_matrix(&self) -> Array2<f32> {
+        match self {
+            Value::Int32(i) => Array2::from_elem((1, 1), *i as f32),
+            Value::Float32(f) => Array2::from_elem((1, 1), *f),
+            Value::Matrix(m) => m.clone(),
+        }
+    }
+}
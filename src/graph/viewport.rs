@@ -0,0 +1,74 @@
+use eframe::egui;
+
+/// Pan/zoom transform for the graph canvas, persisted across frames in egui
+/// memory; `GraphEditor::pan_zoom` holds a copy that
+/// `GraphEditor::update_pan_zoom` syncs from it each frame. Screen position
+/// is derived from world position as `(world + offset) * scale`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewportState {
+    pub scale: f32,
+    pub offset: egui::Vec2,
+}
+
+impl Default for ViewportState {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            offset: egui::Vec2::ZERO,
+        }
+    }
+}
+
+impl ViewportState {
+    pub const MIN_SCALE: f32 = 0.1;
+    pub const MAX_SCALE: f32 = 3.0;
+
+    pub fn to_screen(&self, world: egui::Pos2) -> egui::Pos2 {
+        (world.to_vec2() + self.offset).to_pos2() * self.scale
+    }
+
+    pub fn to_world(&self, screen: egui::Pos2) -> egui::Pos2 {
+        (screen.to_vec2() / self.scale).to_pos2() - self.offset
+    }
+
+    /// Loads the viewport stashed under `id` in egui memory, or the default
+    /// (scale 1, no offset) the first time this id is seen. Uses temp (not
+    /// persisted-to-disk) storage, matching how the editor already keeps
+    /// other per-frame layout state in memory.
+    pub fn load(ctx: &egui::Context, id: egui::Id) -> Self {
+        ctx.memory_mut(|mem| mem.data.get_temp::<Self>(id).unwrap_or_default())
+    }
+
+    pub fn store(self, ctx: &egui::Context, id: egui::Id) {
+        ctx.memory_mut(|mem| mem.data.insert_temp(id, self));
+    }
+
+    /// Applies scroll-to-zoom (anchored on the pointer, so the point under
+    /// the cursor stays fixed) and middle-mouse/space drag-to-pan, using
+    /// input gathered from `response`/`ui` for a canvas occupying `rect`.
+    pub fn handle_input(&mut self, ui: &egui::Ui, rect: egui::Rect, response: &egui::Response) {
+        if let Some(pointer) = ui.ctx().pointer_hover_pos() {
+            if rect.contains(pointer) {
+                let scroll = ui.input(|i| i.scroll_delta.y);
+                if scroll != 0.0 {
+                    let old_scale = self.scale;
+                    let new_scale =
+                        (old_scale * (1.0 + scroll * 0.001)).clamp(Self::MIN_SCALE, Self::MAX_SCALE);
+
+                    // Keep the point under the pointer fixed: solve for the
+                    // offset that maps the same world point to the same
+                    // screen point under the new scale.
+                    let world_under_pointer = pointer.to_vec2() / old_scale - self.offset;
+                    self.offset = pointer.to_vec2() / new_scale - world_under_pointer;
+                    self.scale = new_scale;
+                }
+            }
+        }
+
+        let panning = response.dragged_by(egui::PointerButton::Middle)
+            || (ui.input(|i| i.key_down(egui::Key::Space)) && response.dragged());
+        if panning {
+            self.offset += response.drag_delta() / self.scale;
+        }
+    }
+}
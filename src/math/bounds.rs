@@ -0,0 +1,200 @@
+use num_traits::float::Float;
+
+use super::vector::{Vector2, Vector3};
+
+/// An axis-aligned bounding box in 2D, modeled after pbrt's `Bounds2`. The
+/// default box is "empty" (`min = +inf`, `max = -inf`) so that unioning it
+/// with any point or box just takes that point/box, rather than needing a
+/// special-cased first union.
+///
+/// Bound on `Float` rather than `Vector2`'s own `Ord`-based `min`/`max`
+/// (which `f32`/`f64` don't implement): every corner comparison here goes
+/// through `Float::min`/`Float::max` directly.
+#[derive(Debug, Clone, Copy)]
+pub struct Bounds2<T> {
+    pub min: Vector2<T>,
+    pub max: Vector2<T>,
+}
+
+impl<T: Float> Default for Bounds2<T> {
+    fn default() -> Self {
+        Self {
+            min: Vector2::new(T::infinity(), T::infinity()),
+            max: Vector2::new(T::neg_infinity(), T::neg_infinity()),
+        }
+    }
+}
+
+impl<T: Float> Bounds2<T> {
+    pub fn union_point(self, p: Vector2<T>) -> Self {
+        Self {
+            min: Vector2::new(self.min.x.min(p.x), self.min.y.min(p.y)),
+            max: Vector2::new(self.max.x.max(p.x), self.max.y.max(p.y)),
+        }
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            min: Vector2::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Vector2::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    pub fn intersect(self, other: Self) -> Self {
+        Self {
+            min: Vector2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            max: Vector2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        }
+    }
+
+    pub fn overlaps(self, other: Self) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+    }
+
+    pub fn inside(self, p: Vector2<T>) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
+    }
+
+    pub fn inside_exclusive(self, p: Vector2<T>) -> bool {
+        p.x >= self.min.x && p.x < self.max.x && p.y >= self.min.y && p.y < self.max.y
+    }
+
+    pub fn diagonal(self) -> Vector2<T> {
+        self.max - self.min
+    }
+
+    pub fn area(self) -> T {
+        let d = self.diagonal();
+        d.x * d.y
+    }
+
+    pub fn centroid(self) -> Vector2<T> {
+        (self.min + self.max) * T::from(0.5).unwrap()
+    }
+
+    pub fn expand(self, delta: T) -> Self {
+        Self {
+            min: self.min - Vector2::new(delta, delta),
+            max: self.max + Vector2::new(delta, delta),
+        }
+    }
+}
+
+/// An axis-aligned bounding box in 3D. See `Bounds2` for the rationale
+/// behind the empty-box default and the `Float`-based min/max.
+#[derive(Debug, Clone, Copy)]
+pub struct Bounds3<T> {
+    pub min: Vector3<T>,
+    pub max: Vector3<T>,
+}
+
+impl<T: Float> Default for Bounds3<T> {
+    fn default() -> Self {
+        Self {
+            min: Vector3::new(T::infinity(), T::infinity(), T::infinity()),
+            max: Vector3::new(T::neg_infinity(), T::neg_infinity(), T::neg_infinity()),
+        }
+    }
+}
+
+impl<T: Float> Bounds3<T> {
+    pub fn union_point(self, p: Vector3<T>) -> Self {
+        Self {
+            min: Vector3::new(
+                self.min.x.min(p.x),
+                self.min.y.min(p.y),
+                self.min.z.min(p.z),
+            ),
+            max: Vector3::new(
+                self.max.x.max(p.x),
+                self.max.y.max(p.y),
+                self.max.z.max(p.z),
+            ),
+        }
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            min: Vector3::new(
+                self.min.x.min(other.min.x),
+                self.min.y.min(other.min.y),
+                self.min.z.min(other.min.z),
+            ),
+            max: Vector3::new(
+                self.max.x.max(other.max.x),
+                self.max.y.max(other.max.y),
+                self.max.z.max(other.max.z),
+            ),
+        }
+    }
+
+    pub fn intersect(self, other: Self) -> Self {
+        Self {
+            min: Vector3::new(
+                self.min.x.max(other.min.x),
+                self.min.y.max(other.min.y),
+                self.min.z.max(other.min.z),
+            ),
+            max: Vector3::new(
+                self.max.x.min(other.max.x),
+                self.max.y.min(other.max.y),
+                self.max.z.min(other.max.z),
+            ),
+        }
+    }
+
+    pub fn overlaps(self, other: Self) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    pub fn inside(self, p: Vector3<T>) -> bool {
+        p.x >= self.min.x
+            && p.x <= self.max.x
+            && p.y >= self.min.y
+            && p.y <= self.max.y
+            && p.z >= self.min.z
+            && p.z <= self.max.z
+    }
+
+    pub fn inside_exclusive(self, p: Vector3<T>) -> bool {
+        p.x >= self.min.x
+            && p.x < self.max.x
+            && p.y >= self.min.y
+            && p.y < self.max.y
+            && p.z >= self.min.z
+            && p.z < self.max.z
+    }
+
+    pub fn diagonal(self) -> Vector3<T> {
+        self.max - self.min
+    }
+
+    pub fn surface_area(self) -> T {
+        let d = self.diagonal();
+        (d.x * d.y + d.y * d.z + d.z * d.x) * T::from(2.0).unwrap()
+    }
+
+    pub fn volume(self) -> T {
+        let d = self.diagonal();
+        d.x * d.y * d.z
+    }
+
+    pub fn centroid(self) -> Vector3<T> {
+        (self.min + self.max) * T::from(0.5).unwrap()
+    }
+
+    pub fn expand(self, delta: T) -> Self {
+        Self {
+            min: self.min - Vector3::new(delta, delta, delta),
+            max: self.max + Vector3::new(delta, delta, delta),
+        }
+    }
+}
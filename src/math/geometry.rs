@@ -0,0 +1,99 @@
+use num_traits::float::Float;
+
+use super::vector::{Vector2, Vector3, Vector4};
+
+impl<T: Float> Vector2<T> {
+    pub fn distance(self, rhs: Self) -> T {
+        (self - rhs).length()
+    }
+
+    pub fn distance_squared(self, rhs: Self) -> T {
+        let d = self - rhs;
+        d.dot(d)
+    }
+
+    pub fn reflect(self, n: Self) -> Self {
+        self - n * (self.dot(n) * T::from(2.0).unwrap())
+    }
+
+    pub fn faceforward(self, reference: Self) -> Self {
+        if self.dot(reference) < T::zero() {
+            self * -T::one()
+        } else {
+            self
+        }
+    }
+}
+
+pub fn lerp2<T: Float>(t: T, a: Vector2<T>, b: Vector2<T>) -> Vector2<T> {
+    a * (T::one() - t) + b * t
+}
+
+impl<T: Float> Vector3<T> {
+    pub fn distance(self, rhs: Self) -> T {
+        (self - rhs).length()
+    }
+
+    pub fn distance_squared(self, rhs: Self) -> T {
+        let d = self - rhs;
+        d.dot(d)
+    }
+
+    pub fn reflect(self, n: Self) -> Self {
+        self - n * (self.dot(n) * T::from(2.0).unwrap())
+    }
+
+    pub fn faceforward(self, reference: Self) -> Self {
+        if self.dot(reference) < T::zero() {
+            self * -T::one()
+        } else {
+            self
+        }
+    }
+
+    /// Given one normalized vector, produces two more that together form a
+    /// right-handed orthonormal basis with it. Picks whichever of `x`/`y`
+    /// has the larger magnitude to build a perpendicular that stays stable
+    /// (avoids near-zero components) as `self` sweeps through all
+    /// directions.
+    pub fn coordinate_system(self) -> (Self, Self) {
+        let v2 = if self.x.abs() > self.y.abs() {
+            Vector3::new(-self.z, T::zero(), self.x) / (self.x * self.x + self.z * self.z).sqrt()
+        } else {
+            Vector3::new(T::zero(), self.z, -self.y) / (self.y * self.y + self.z * self.z).sqrt()
+        };
+        let v3 = self.cross(v2);
+        (v2, v3)
+    }
+}
+
+pub fn lerp3<T: Float>(t: T, a: Vector3<T>, b: Vector3<T>) -> Vector3<T> {
+    a * (T::one() - t) + b * t
+}
+
+impl<T: Float> Vector4<T> {
+    pub fn distance(self, rhs: Self) -> T {
+        (self - rhs).length()
+    }
+
+    pub fn distance_squared(self, rhs: Self) -> T {
+        let d = self - rhs;
+        d.dot(d)
+    }
+
+    pub fn reflect(self, n: Self) -> Self {
+        self - n * (self.dot(n) * T::from(2.0).unwrap())
+    }
+
+    pub fn faceforward(self, reference: Self) -> Self {
+        if self.dot(reference) < T::zero() {
+            self * -T::one()
+        } else {
+            self
+        }
+    }
+}
+
+pub fn lerp4<T: Float>(t: T, a: Vector4<T>, b: Vector4<T>) -> Vector4<T> {
+    a * (T::one() - t) + b * t
+}
@@ -0,0 +1,54 @@
+use num_traits::float::Float;
+
+use super::vector::Vector2;
+
+/// Convex hull of `points` via Andrew's monotone chain, returned
+/// counter-clockwise starting from the lexicographically smallest point.
+///
+/// Collinear points along a hull edge are dropped (the turn test uses `<= 0`,
+/// not `< 0`), so the result is the minimal vertex set describing the hull
+/// rather than every point that happens to lie on its boundary.
+///
+/// Fewer than 3 points (after removing exact duplicates) are returned sorted
+/// by `(x, y)` without further processing;
+/// all-collinear input collapses to its two extreme points.
+pub fn convex_hull<T: Float>(points: &[Vector2<T>]) -> Vec<Vector2<T>> {
+    let mut sorted: Vec<Vector2<T>> = points.to_vec();
+    sorted.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap()
+            .then(a.y.partial_cmp(&b.y).unwrap())
+    });
+    sorted.dedup_by(|a, b| a.x == b.x && a.y == b.y);
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    // Non-positive cross product means `c` is not a strict left turn from
+    // `a -> b`, so `b` doesn't belong on the hull and should be popped.
+    let turn = |a: Vector2<T>, b: Vector2<T>, c: Vector2<T>| (b - a).cross(c - a);
+
+    let mut lower: Vec<Vector2<T>> = Vec::new();
+    for &p in &sorted {
+        while lower.len() >= 2 && turn(lower[lower.len() - 2], lower[lower.len() - 1], p) <= T::zero()
+        {
+            lower.pop();
+        }
+        lower.push(p);
+    }
+
+    let mut upper: Vec<Vector2<T>> = Vec::new();
+    for &p in sorted.iter().rev() {
+        while upper.len() >= 2 && turn(upper[upper.len() - 2], upper[upper.len() - 1], p) <= T::zero()
+        {
+            upper.pop();
+        }
+        upper.push(p);
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
@@ -0,0 +1,311 @@
+use num_traits::float::Float;
+use std::ops::Mul;
+
+use super::vector::{Vector3, Vector4};
+
+/// Row-major 3x3 matrix: `m[row][col]`, and `Mul<Vector3>` treats the vector
+/// as a column, i.e. `row.dot(v)` per output component.
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix3<T> {
+    pub m: [[T; 3]; 3],
+}
+
+impl<T: Float> Matrix3<T> {
+    pub fn identity() -> Self {
+        let zero = T::zero();
+        let one = T::one();
+        Self {
+            m: [
+                [one, zero, zero],
+                [zero, one, zero],
+                [zero, zero, one],
+            ],
+        }
+    }
+
+    pub fn transpose(self) -> Self {
+        let m = self.m;
+        Self {
+            m: [
+                [m[0][0], m[1][0], m[2][0]],
+                [m[0][1], m[1][1], m[2][1]],
+                [m[0][2], m[1][2], m[2][2]],
+            ],
+        }
+    }
+
+    pub fn determinant(self) -> T {
+        let m = self.m;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Inverse via the adjugate (cofactor transpose) over the determinant.
+    /// Returns `None` if the determinant is near zero.
+    pub fn inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if det.abs() < T::epsilon() {
+            return None;
+        }
+        let m = self.m;
+        let inv_det = T::one() / det;
+        let cofactor = |r0: usize, c0: usize, r1: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+        // adjugate[row][col] = cofactor of the transposed (col, row) minor
+        let adj = [
+            [
+                cofactor(1, 1, 2, 2),
+                cofactor(0, 2, 2, 1),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                cofactor(1, 2, 2, 0),
+                cofactor(0, 0, 2, 2),
+                cofactor(0, 2, 1, 0),
+            ],
+            [
+                cofactor(1, 0, 2, 1),
+                cofactor(0, 1, 2, 0),
+                cofactor(0, 0, 1, 1),
+            ],
+        ];
+        Some(Self {
+            m: [
+                [adj[0][0] * inv_det, adj[0][1] * inv_det, adj[0][2] * inv_det],
+                [adj[1][0] * inv_det, adj[1][1] * inv_det, adj[1][2] * inv_det],
+                [adj[2][0] * inv_det, adj[2][1] * inv_det, adj[2][2] * inv_det],
+            ],
+        })
+    }
+}
+
+impl<T: Float> Mul for Matrix3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let a = self.m;
+        let b = rhs.m;
+        let mut out = [[T::zero(); 3]; 3];
+        for row in 0..3 {
+            for col in 0..3 {
+                out[row][col] =
+                    a[row][0] * b[0][col] + a[row][1] * b[1][col] + a[row][2] * b[2][col];
+            }
+        }
+        Self { m: out }
+    }
+}
+
+impl<T: Float> Mul<Vector3<T>> for Matrix3<T> {
+    type Output = Vector3<T>;
+
+    fn mul(self, rhs: Vector3<T>) -> Vector3<T> {
+        let m = self.m;
+        Vector3::new(
+            m[0][0] * rhs.x + m[0][1] * rhs.y + m[0][2] * rhs.z,
+            m[1][0] * rhs.x + m[1][1] * rhs.y + m[1][2] * rhs.z,
+            m[2][0] * rhs.x + m[2][1] * rhs.y + m[2][2] * rhs.z,
+        )
+    }
+}
+
+/// Row-major 4x4 matrix, used for affine transforms (translation lives in
+/// column 3 of the top three rows, matching `Vector4`'s `w` convention for
+/// points vs. directions).
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix4<T> {
+    pub m: [[T; 4]; 4],
+}
+
+impl<T: Float> Matrix4<T> {
+    pub fn identity() -> Self {
+        let zero = T::zero();
+        let one = T::one();
+        Self {
+            m: [
+                [one, zero, zero, zero],
+                [zero, one, zero, zero],
+                [zero, zero, one, zero],
+                [zero, zero, zero, one],
+            ],
+        }
+    }
+
+    pub fn transpose(self) -> Self {
+        let m = self.m;
+        let mut out = [[T::zero(); 4]; 4];
+        for row in 0..4 {
+            for col in 0..4 {
+                out[row][col] = m[col][row];
+            }
+        }
+        Self { m: out }
+    }
+
+    pub fn translation(t: Vector3<T>) -> Self {
+        let mut out = Self::identity();
+        out.m[0][3] = t.x;
+        out.m[1][3] = t.y;
+        out.m[2][3] = t.z;
+        out
+    }
+
+    pub fn scale(s: Vector3<T>) -> Self {
+        let zero = T::zero();
+        let one = T::one();
+        Self {
+            m: [
+                [s.x, zero, zero, zero],
+                [zero, s.y, zero, zero],
+                [zero, zero, s.z, zero],
+                [zero, zero, zero, one],
+            ],
+        }
+    }
+
+    /// Rotation by `angle` radians about `axis` (need not be pre-normalized),
+    /// via Rodrigues' rotation formula.
+    pub fn rotation(axis: Vector3<T>, angle: T) -> Self {
+        let a = axis.normalize();
+        let (s, c) = angle.sin_cos();
+        let t = T::one() - c;
+        let zero = T::zero();
+        Self {
+            m: [
+                [
+                    t * a.x * a.x + c,
+                    t * a.x * a.y - s * a.z,
+                    t * a.x * a.z + s * a.y,
+                    zero,
+                ],
+                [
+                    t * a.x * a.y + s * a.z,
+                    t * a.y * a.y + c,
+                    t * a.y * a.z - s * a.x,
+                    zero,
+                ],
+                [
+                    t * a.x * a.z - s * a.y,
+                    t * a.y * a.z + s * a.x,
+                    t * a.z * a.z + c,
+                    zero,
+                ],
+                [zero, zero, zero, T::one()],
+            ],
+        }
+    }
+
+    /// A right-handed view matrix placing `eye` at the origin looking toward
+    /// `center`, with `up` completing the basis.
+    pub fn look_at(eye: Vector3<T>, center: Vector3<T>, up: Vector3<T>) -> Self {
+        let forward = (center - eye).normalize();
+        let side = forward.cross(up).normalize();
+        let true_up = side.cross(forward);
+        let zero = T::zero();
+        Self {
+            m: [
+                [side.x, side.y, side.z, -side.dot(eye)],
+                [true_up.x, true_up.y, true_up.z, -true_up.dot(eye)],
+                [-forward.x, -forward.y, -forward.z, forward.dot(eye)],
+                [zero, zero, zero, T::one()],
+            ],
+        }
+    }
+
+    pub fn determinant(self) -> T {
+        self.gauss_jordan(false).0
+    }
+
+    /// Inverse via Gauss-Jordan elimination with partial pivoting: augment
+    /// `self` with the identity, then for each column pick the pivot row
+    /// with the largest absolute value in that column, normalize it, and
+    /// eliminate the column from every other row. Returns `None` if a pivot
+    /// is too close to zero (the matrix is singular).
+    pub fn inverse(self) -> Option<Self> {
+        self.gauss_jordan(true).1
+    }
+
+    /// Shared Gauss-Jordan pass: always computes the determinant as the
+    /// product of the pivots (times the row-swap sign), and additionally
+    /// builds the inverse when `want_inverse` is set.
+    fn gauss_jordan(self, want_inverse: bool) -> (T, Option<Self>) {
+        let mut a = self.m;
+        let mut inv = Matrix4::<T>::identity().m;
+        let mut det = T::one();
+
+        for col in 0..4 {
+            let mut pivot_row = col;
+            let mut pivot_val = a[col][col].abs();
+            for row in (col + 1)..4 {
+                if a[row][col].abs() > pivot_val {
+                    pivot_row = row;
+                    pivot_val = a[row][col].abs();
+                }
+            }
+            if pivot_val < T::epsilon() {
+                return (T::zero(), None);
+            }
+            if pivot_row != col {
+                a.swap(col, pivot_row);
+                inv.swap(col, pivot_row);
+                det = -det;
+            }
+
+            let pivot = a[col][col];
+            det = det * pivot;
+            for c in 0..4 {
+                a[col][c] = a[col][c] / pivot;
+                inv[col][c] = inv[col][c] / pivot;
+            }
+
+            for row in 0..4 {
+                if row == col {
+                    continue;
+                }
+                let factor = a[row][col];
+                for c in 0..4 {
+                    a[row][c] = a[row][c] - factor * a[col][c];
+                    inv[row][c] = inv[row][c] - factor * inv[col][c];
+                }
+            }
+        }
+
+        let inverse = if want_inverse { Some(Self { m: inv }) } else { None };
+        (det, inverse)
+    }
+}
+
+impl<T: Float> Mul for Matrix4<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let a = self.m;
+        let b = rhs.m;
+        let mut out = [[T::zero(); 4]; 4];
+        for row in 0..4 {
+            for col in 0..4 {
+                out[row][col] = a[row][0] * b[0][col]
+                    + a[row][1] * b[1][col]
+                    + a[row][2] * b[2][col]
+                    + a[row][3] * b[3][col];
+            }
+        }
+        Self { m: out }
+    }
+}
+
+impl<T: Float> Mul<Vector4<T>> for Matrix4<T> {
+    type Output = Vector4<T>;
+
+    fn mul(self, rhs: Vector4<T>) -> Vector4<T> {
+        let m = self.m;
+        Vector4::new(
+            m[0][0] * rhs.x + m[0][1] * rhs.y + m[0][2] * rhs.z + m[0][3] * rhs.w,
+            m[1][0] * rhs.x + m[1][1] * rhs.y + m[1][2] * rhs.z + m[1][3] * rhs.w,
+            m[2][0] * rhs.x + m[2][1] * rhs.y + m[2][2] * rhs.z + m[2][3] * rhs.w,
+            m[3][0] * rhs.x + m[3][1] * rhs.y + m[3][2] * rhs.z + m[3][3] * rhs.w,
+        )
+    }
+}
@@ -157,6 +157,25 @@ impl<T: PartialOrd + Ord + Copy> Vector2<T> {
     }
 }
 
+impl<T: Mul<Output = T> + Sub<Output = T> + Copy> Vector2<T> {
+    /// Signed 2D cross product (perp-dot product): `x*rhs.y - y*rhs.x`.
+    /// Positive when `rhs` is a counter-clockwise turn from `self`; its
+    /// absolute value is twice the area of the triangle `(0, self, rhs)`.
+    pub fn cross(self, rhs: Self) -> T {
+        self.x * rhs.y - self.y * rhs.x
+    }
+}
+
+impl<T: std::ops::Neg<Output = T> + Copy> Vector2<T> {
+    /// Rotates this vector 90° counter-clockwise.
+    pub fn perp(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+}
+
 impl<T> Vector3<T> {
     pub fn new(x: T, y: T, z: T) -> Self {
         Self { x, y, z }
@@ -300,3 +319,176 @@ impl<T: PartialOrd + Ord + Copy> Vector3<T> {
         }
     }
 }
+
+impl<T: Mul<Output = T> + Sub<Output = T> + Copy> Vector3<T> {
+    /// Cross product: `(y*rhs.z - z*rhs.y, z*rhs.x - x*rhs.z, x*rhs.y - y*rhs.x)`,
+    /// the vector perpendicular to both `self` and `rhs` (right-handed).
+    ///
+    /// This is the plain three-subtraction formula, not the
+    /// difference-of-products (`a*b - c*d` computed so as to minimize
+    /// rounding) variant; callers relying on near-degenerate triangle
+    /// normals for floating types may want to upgrade to that for better
+    /// cancellation behavior.
+    pub fn cross(self, rhs: Self) -> Self {
+        Self {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+}
+
+impl<T> Vector4<T> {
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vector4<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+            w: self.w + rhs.w,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vector4<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+            w: self.w - rhs.w,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vector4<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        Self {
+            x: self.x * scalar,
+            y: self.y * scalar,
+            z: self.z * scalar,
+            w: self.w * scalar,
+        }
+    }
+}
+
+impl<T: Div<Output = T> + Copy> Div<T> for Vector4<T> {
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self {
+        Self {
+            x: self.x / scalar,
+            y: self.y / scalar,
+            z: self.z / scalar,
+            w: self.w / scalar,
+        }
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<Vector4<T>> for Vector4<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+            z: self.z * rhs.z,
+            w: self.w * rhs.w,
+        }
+    }
+}
+
+impl<T: Div<Output = T>> Div for Vector4<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self {
+            x: self.x / rhs.x,
+            y: self.y / rhs.y,
+            z: self.z / rhs.z,
+            w: self.w / rhs.w,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Add<Output = T> + Copy> Vector4<T> {
+    pub fn dot(self, rhs: Self) -> T {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+}
+
+impl<T> Index<usize> for Vector4<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("Index out of bounds"),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vector4<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("Index out of bounds"),
+        }
+    }
+}
+
+impl<T: num_traits::float::Float> Vector4<T> {
+    pub fn length(self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(self) -> Self {
+        self / self.length()
+    }
+
+    pub fn abs(self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+            w: self.w.abs(),
+        }
+    }
+}
+
+impl<T: PartialOrd + Ord + Copy> Vector4<T> {
+    pub fn min(self, rhs: Self) -> Self {
+        Self {
+            x: min(self.x, rhs.x),
+            y: min(self.y, rhs.y),
+            z: min(self.z, rhs.z),
+            w: min(self.w, rhs.w),
+        }
+    }
+
+    pub fn max(self, rhs: Self) -> Self {
+        Self {
+            x: max(self.x, rhs.x),
+            y: max(self.y, rhs.y),
+            z: max(self.z, rhs.z),
+            w: max(self.w, rhs.w),
+        }
+    }
+}
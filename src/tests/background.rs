@@ -0,0 +1,41 @@
+use super::super::graph;
+use graph::background::grid_lines;
+
+#[test]
+fn grid_lines_world_origin_matches_to_screen() {
+    // `ViewportState::to_screen` maps world (0, 0) to `offset * scale`; the
+    // grid's own "origin" (the col/row-0 line) must land there too, or the
+    // grid drifts relative to the nodes drawn on top of it.
+    let scale = 2.0;
+    let offset_x = 30.0;
+    let cell = 25.0 * scale;
+    let origin = offset_x * scale;
+
+    let lines = grid_lines(-1000.0, 1000.0, origin, cell, 5);
+    assert!(lines.iter().any(|&(pos, _)| (pos - origin).abs() < 1e-4));
+}
+
+#[test]
+fn grid_lines_marks_every_nth_as_thick() {
+    let lines = grid_lines(0.0, 100.0, 0.0, 10.0, 5);
+    // Cell index 0 (the origin) and 5 (the next multiple of 5) should both
+    // be thick; index 1..4 should not be.
+    let thick_at = |pos: f32| lines.iter().find(|&&(p, _)| (p - pos).abs() < 1e-4).map(|&(_, t)| t);
+    assert_eq!(thick_at(0.0), Some(true));
+    assert_eq!(thick_at(50.0), Some(true));
+    assert_eq!(thick_at(10.0), Some(false));
+}
+
+#[test]
+fn grid_lines_thick_every_zero_disables_thick_lines() {
+    let lines = grid_lines(0.0, 50.0, 0.0, 10.0, 0);
+    assert!(lines.iter().all(|&(_, thick)| !thick));
+}
+
+#[test]
+fn grid_lines_covers_the_full_requested_range() {
+    let lines = grid_lines(-5.0, 5.0, 0.0, 10.0, 5);
+    let positions: Vec<f32> = lines.iter().map(|&(pos, _)| pos).collect();
+    assert!(positions.iter().any(|&p| p <= -5.0));
+    assert!(positions.iter().any(|&p| p >= 5.0));
+}
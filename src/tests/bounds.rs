@@ -0,0 +1,126 @@
+use super::super::math;
+use math::bounds::{Bounds2, Bounds3};
+use math::vector::{Vector2, Vector3};
+
+#[test]
+fn bounds2_default_is_empty_so_union_point_just_takes_the_point() {
+    let empty = Bounds2::<f32>::default();
+    let p = Vector2::new(1.0, 2.0);
+    let b = empty.union_point(p);
+    assert_eq!((b.min.x, b.min.y), (1.0, 2.0));
+    assert_eq!((b.max.x, b.max.y), (1.0, 2.0));
+}
+
+#[test]
+fn bounds2_union_covers_both_boxes() {
+    let a = Bounds2::<f32>::default()
+        .union_point(Vector2::new(0.0, 0.0))
+        .union_point(Vector2::new(1.0, 1.0));
+    let b = Bounds2::<f32>::default()
+        .union_point(Vector2::new(2.0, -1.0))
+        .union_point(Vector2::new(3.0, 0.5));
+
+    let u = a.union(b);
+    assert_eq!((u.min.x, u.min.y), (0.0, -1.0));
+    assert_eq!((u.max.x, u.max.y), (3.0, 1.0));
+}
+
+#[test]
+fn bounds2_intersect_and_overlaps() {
+    let a = Bounds2 {
+        min: Vector2::new(0.0, 0.0),
+        max: Vector2::new(2.0, 2.0),
+    };
+    let b = Bounds2 {
+        min: Vector2::new(1.0, 1.0),
+        max: Vector2::new(3.0, 3.0),
+    };
+
+    assert!(a.overlaps(b));
+    let i = a.intersect(b);
+    assert_eq!((i.min.x, i.min.y), (1.0, 1.0));
+    assert_eq!((i.max.x, i.max.y), (2.0, 2.0));
+
+    let c = Bounds2 {
+        min: Vector2::new(5.0, 5.0),
+        max: Vector2::new(6.0, 6.0),
+    };
+    assert!(!a.overlaps(c));
+}
+
+#[test]
+fn bounds2_inside_is_inclusive_and_inside_exclusive_is_not() {
+    let b = Bounds2 {
+        min: Vector2::new(0.0, 0.0),
+        max: Vector2::new(1.0, 1.0),
+    };
+    assert!(b.inside(Vector2::new(1.0, 1.0)));
+    assert!(!b.inside_exclusive(Vector2::new(1.0, 1.0)));
+    assert!(b.inside_exclusive(Vector2::new(0.0, 0.0)));
+}
+
+#[test]
+fn bounds2_diagonal_area_centroid_and_expand() {
+    let b = Bounds2 {
+        min: Vector2::new(0.0, 0.0),
+        max: Vector2::new(4.0, 2.0),
+    };
+    let d = b.diagonal();
+    assert_eq!((d.x, d.y), (4.0, 2.0));
+    assert_eq!(b.area(), 8.0);
+
+    let c = b.centroid();
+    assert_eq!((c.x, c.y), (2.0, 1.0));
+
+    let expanded = b.expand(1.0);
+    assert_eq!((expanded.min.x, expanded.min.y), (-1.0, -1.0));
+    assert_eq!((expanded.max.x, expanded.max.y), (5.0, 3.0));
+}
+
+#[test]
+fn bounds3_default_is_empty_so_union_point_just_takes_the_point() {
+    let empty = Bounds3::<f32>::default();
+    let p = Vector3::new(1.0, 2.0, 3.0);
+    let b = empty.union_point(p);
+    assert_eq!((b.min.x, b.min.y, b.min.z), (1.0, 2.0, 3.0));
+    assert_eq!((b.max.x, b.max.y, b.max.z), (1.0, 2.0, 3.0));
+}
+
+#[test]
+fn bounds3_intersect_overlaps_and_inside() {
+    let a = Bounds3 {
+        min: Vector3::new(0.0, 0.0, 0.0),
+        max: Vector3::new(2.0, 2.0, 2.0),
+    };
+    let b = Bounds3 {
+        min: Vector3::new(1.0, 1.0, 1.0),
+        max: Vector3::new(3.0, 3.0, 3.0),
+    };
+
+    assert!(a.overlaps(b));
+    let i = a.intersect(b);
+    assert_eq!((i.min.x, i.min.y, i.min.z), (1.0, 1.0, 1.0));
+    assert_eq!((i.max.x, i.max.y, i.max.z), (2.0, 2.0, 2.0));
+
+    assert!(a.inside(Vector3::new(2.0, 2.0, 2.0)));
+    assert!(!a.inside_exclusive(Vector3::new(2.0, 2.0, 2.0)));
+}
+
+#[test]
+fn bounds3_surface_area_volume_centroid_and_expand() {
+    let b = Bounds3 {
+        min: Vector3::new(0.0, 0.0, 0.0),
+        max: Vector3::new(2.0, 3.0, 4.0),
+    };
+
+    // surface_area = 2*(xy + yz + zx) = 2*(6 + 12 + 8) = 52
+    assert_eq!(b.surface_area(), 52.0);
+    assert_eq!(b.volume(), 24.0);
+
+    let c = b.centroid();
+    assert_eq!((c.x, c.y, c.z), (1.0, 1.5, 2.0));
+
+    let expanded = b.expand(1.0);
+    assert_eq!((expanded.min.x, expanded.min.y, expanded.min.z), (-1.0, -1.0, -1.0));
+    assert_eq!((expanded.max.x, expanded.max.y, expanded.max.z), (3.0, 4.0, 5.0));
+}
@@ -0,0 +1,88 @@
+use eframe::egui;
+
+use super::super::graph;
+use graph::command::{CommandHistory, Connect, MoveNode, RemoveNode};
+use graph::graph::Graph;
+
+#[test]
+fn removing_and_undoing_a_node_restores_its_slots_data_type_and_value() {
+    // `Graph<f32, &str, f32>` gives `Input`/`Output` real `typ`/`value`
+    // payloads (unlike the `()`-typed graphs the other tests in this file
+    // use), so losing them on undo would actually be observable.
+    let mut graph = Graph::<f32, &'static str, f32>::new();
+    let mut history = CommandHistory::<f32, &'static str, f32>::new();
+
+    let node = graph.add_node("n".into(), 1.0);
+    let input = graph.add_input_param(node, "in".into(), "float", 2.5);
+    let output = graph.add_output_param(node, "out".into(), "float");
+
+    history.push(Box::new(RemoveNode::new(node)), &mut graph);
+    assert!(graph.inputs.get(input).is_none());
+    assert!(graph.outputs.get(output).is_none());
+
+    let restore = history.undo(&mut graph);
+    let new_node = restore.remap.expect("RemoveNode::undo should report a remap").1;
+
+    let new_input = graph.nodes[new_node].inputs[0].1;
+    let new_output = graph.nodes[new_node].outputs[0].1;
+    assert_eq!(graph.inputs[new_input].typ, "float");
+    assert_eq!(graph.inputs[new_input].value, 2.5);
+    assert_eq!(graph.outputs[new_output].typ, "float");
+}
+
+#[test]
+fn undoing_a_connect_after_its_node_was_removed_and_restored_disconnects_the_live_slot() {
+    let mut graph = Graph::<f32>::new();
+    let mut history = CommandHistory::<f32>::new();
+
+    let source = graph.add_node("source".into(), 1.0);
+    let source_out = graph.add_output_param(source, "out".into(), ());
+
+    let node_a = graph.add_node("a".into(), 2.0);
+    let a_in = graph.add_input_param(node_a, "in".into(), (), ());
+
+    history.push(Box::new(Connect::new(a_in, source_out)), &mut graph);
+    assert_eq!(graph.connection(a_in), Some(source_out));
+
+    // Deleting `a` tears down the connection along with its slot.
+    history.push(Box::new(RemoveNode::new(node_a)), &mut graph);
+    assert_eq!(graph.connection(a_in), None);
+
+    // Undo the delete: `a` and its input slot come back under fresh ids, and
+    // the connection is restored against the new slot id.
+    let restore = history.undo(&mut graph);
+    let new_node_a = restore.remap.expect("RemoveNode::undo should report a remap").1;
+    let new_a_in = graph.nodes[new_node_a].inputs[0].1;
+    assert_ne!(new_a_in, a_in);
+    assert_eq!(graph.connection(new_a_in), Some(source_out));
+
+    // Undo the connect: without resolving `Connect`'s stale `a_in` through
+    // the remap table, this would silently no-op and leave the restored
+    // connection live.
+    history.undo(&mut graph);
+    assert_eq!(graph.connection(new_a_in), None);
+}
+
+#[test]
+fn move_node_resolves_its_node_id_after_a_remove_and_restore() {
+    let mut graph = Graph::<f32>::new();
+    let mut history = CommandHistory::<f32>::new();
+
+    let node = graph.add_node("m".into(), 1.0);
+    let delta = egui::Vec2::new(5.0, 5.0);
+
+    let moved = history.push(Box::new(MoveNode::new(node, delta)), &mut graph);
+    assert_eq!(moved.move_delta, Some((node, delta)));
+
+    let removed = history.push(Box::new(RemoveNode::new(node)), &mut graph);
+    assert!(removed.remap.is_none());
+
+    let restored = history.undo(&mut graph);
+    let new_node = restored.remap.expect("RemoveNode::undo should report a remap").1;
+
+    // The `MoveNode` below `RemoveNode` on the stack was pushed against the
+    // old `node` id; undoing it now should report the delta against the id
+    // the node actually lives under today.
+    let undone_move = history.undo(&mut graph);
+    assert_eq!(undone_move.move_delta, Some((new_node, delta)));
+}
@@ -0,0 +1,131 @@
+use std::cell::Cell;
+
+use super::super::graph;
+use graph::eval::{EvalError, Evaluator, NodeDataTrait};
+use graph::graph::Graph;
+use graph::value::Value;
+
+/// A node whose behavior depends on how it's evaluated, letting every test
+/// below share one `Graph<TestNode>`:
+/// - `Source` ignores its (nonexistent) inputs and always emits one constant
+///   output, tracking how many times it actually ran.
+/// - `PassThrough` forwards its single input to its single output.
+/// - `Sink` has no outputs; its inputs are just dropped.
+enum TestNode {
+    Source { run_count: Cell<u32> },
+    PassThrough,
+    Sink,
+}
+
+impl NodeDataTrait for TestNode {
+    fn evaluate(&self, inputs: &[Value]) -> Vec<Value> {
+        match self {
+            TestNode::Source { run_count } => {
+                run_count.set(run_count.get() + 1);
+                vec![Value::Int32(1)]
+            }
+            TestNode::PassThrough => inputs.to_vec(),
+            TestNode::Sink => Vec::new(),
+        }
+    }
+
+    fn input_constant(&self, _local_index: usize) -> Value {
+        Value::Int32(0)
+    }
+}
+
+#[test]
+fn evaluate_output_less_sink_node_runs() {
+    let mut graph = Graph::<TestNode>::new();
+    let sink = graph.add_node("sink".into(), TestNode::Sink);
+
+    let mut evaluator = Evaluator::new(&graph);
+    let outputs = evaluator.evaluate(sink).expect("sink should evaluate");
+    assert!(outputs.is_empty());
+}
+
+#[test]
+fn evaluate_detects_cycle() {
+    let mut graph = Graph::<TestNode>::new();
+    let a = graph.add_node("a".into(), TestNode::PassThrough);
+    let a_in = graph.add_input_param(a, "in".into(), (), ());
+    let a_out = graph.add_output_param(a, "out".into(), ());
+    // Feed the node's own output back into its input.
+    graph.add_connection(a_in, a_out);
+
+    let mut evaluator = Evaluator::new(&graph);
+    let result = evaluator.evaluate(a);
+    assert_eq!(result, Err(EvalError::Cycle(a)));
+}
+
+#[test]
+fn evaluate_does_not_leak_visiting_state_across_calls() {
+    let mut graph = Graph::<TestNode>::new();
+
+    // `a` and `b` form a genuine cycle; `x` merely depends on `a` and is not
+    // itself part of it.
+    let a = graph.add_node("a".into(), TestNode::PassThrough);
+    let a_in = graph.add_input_param(a, "in".into(), (), ());
+    let a_out = graph.add_output_param(a, "out".into(), ());
+
+    let b = graph.add_node("b".into(), TestNode::PassThrough);
+    let b_in = graph.add_input_param(b, "in".into(), (), ());
+    let b_out = graph.add_output_param(b, "out".into(), ());
+
+    graph.add_connection(a_in, b_out);
+    graph.add_connection(b_in, a_out);
+
+    let x = graph.add_node("x".into(), TestNode::PassThrough);
+    let x_in = graph.add_input_param(x, "in".into(), (), ());
+    graph.add_connection(x_in, a_out);
+
+    let mut evaluator = Evaluator::new(&graph);
+
+    // The first call fails partway through, deep inside `a`'s and `b`'s
+    // recursive frames. If those frames didn't clean up `visiting` on the
+    // way out, `a`, `b`, and `x` would all be stuck "visiting" forever.
+    assert_eq!(evaluator.evaluate(x), Err(EvalError::Cycle(a)));
+
+    // A second, independent call on the same `Evaluator` must see the exact
+    // same (correct) cycle, not a spurious `Cycle(x)` from leftover state.
+    assert_eq!(evaluator.evaluate(x), Err(EvalError::Cycle(a)));
+}
+
+#[test]
+fn evaluate_memoizes_diamond_shaped_dependency() {
+    let mut graph = Graph::<TestNode>::new();
+
+    let source = graph.add_node(
+        "source".into(),
+        TestNode::Source {
+            run_count: Cell::new(0),
+        },
+    );
+    let source_out = graph.add_output_param(source, "out".into(), ());
+
+    let left = graph.add_node("left".into(), TestNode::PassThrough);
+    let left_in = graph.add_input_param(left, "in".into(), (), ());
+    let left_out = graph.add_output_param(left, "out".into(), ());
+    graph.add_connection(left_in, source_out);
+
+    let right = graph.add_node("right".into(), TestNode::PassThrough);
+    let right_in = graph.add_input_param(right, "in".into(), (), ());
+    let right_out = graph.add_output_param(right, "out".into(), ());
+    graph.add_connection(right_in, source_out);
+
+    let sink = graph.add_node("sink".into(), TestNode::Sink);
+    let sink_in_left = graph.add_input_param(sink, "left".into(), (), ());
+    let sink_in_right = graph.add_input_param(sink, "right".into(), (), ());
+    graph.add_connection(sink_in_left, left_out);
+    graph.add_connection(sink_in_right, right_out);
+
+    let mut evaluator = Evaluator::new(&graph);
+    evaluator.evaluate(sink).expect("diamond graph should evaluate");
+
+    // Without memoization, `source` would run once per path (2); with it,
+    // it runs exactly once despite feeding both `left` and `right`.
+    match &graph.nodes[source].data {
+        TestNode::Source { run_count } => assert_eq!(run_count.get(), 1),
+        _ => unreachable!(),
+    }
+}
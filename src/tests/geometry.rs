@@ -0,0 +1,95 @@
+use super::super::math;
+use math::geometry::{lerp2, lerp3, lerp4};
+use math::vector::{Vector2, Vector3, Vector4};
+
+#[test]
+fn distance_and_distance_squared_match_for_a_3_4_5_triangle() {
+    let a = Vector2::new(0.0, 0.0);
+    let b = Vector2::new(3.0, 4.0);
+    assert_eq!(a.distance(b), 5.0);
+    assert_eq!(a.distance_squared(b), 25.0);
+}
+
+#[test]
+fn reflect_bounces_a_vector_off_a_normal_like_a_mirror() {
+    // Incoming (1, -1) hits the floor normal (0, 1) and should bounce to (1, 1).
+    let incoming = Vector2::new(1.0, -1.0);
+    let normal = Vector2::new(0.0, 1.0);
+    let reflected = incoming.reflect(normal);
+    assert_eq!((reflected.x, reflected.y), (1.0, 1.0));
+}
+
+#[test]
+fn faceforward_flips_only_when_pointing_away_from_the_reference() {
+    let reference = Vector2::new(1.0, 0.0);
+
+    let aligned = Vector2::new(1.0, 1.0);
+    let kept = aligned.faceforward(reference);
+    assert_eq!((kept.x, kept.y), (aligned.x, aligned.y));
+
+    let opposed = Vector2::new(-1.0, 1.0);
+    let flipped = opposed.faceforward(reference);
+    assert_eq!((flipped.x, flipped.y), (1.0, -1.0));
+}
+
+#[test]
+fn lerp2_interpolates_between_endpoints() {
+    let a = Vector2::new(0.0, 0.0);
+    let b = Vector2::new(10.0, 20.0);
+    assert_eq!((lerp2(0.0, a, b).x, lerp2(0.0, a, b).y), (0.0, 0.0));
+    assert_eq!((lerp2(1.0, a, b).x, lerp2(1.0, a, b).y), (10.0, 20.0));
+    assert_eq!((lerp2(0.5, a, b).x, lerp2(0.5, a, b).y), (5.0, 10.0));
+}
+
+#[test]
+fn vector3_distance_reflect_and_lerp3() {
+    let a = Vector3::new(0.0, 0.0, 0.0);
+    let b = Vector3::new(1.0, 2.0, 2.0);
+    assert_eq!(a.distance(b), 3.0);
+    assert_eq!(a.distance_squared(b), 9.0);
+
+    let incoming = Vector3::new(1.0, -1.0, 0.0);
+    let normal = Vector3::new(0.0, 1.0, 0.0);
+    let reflected = incoming.reflect(normal);
+    assert_eq!((reflected.x, reflected.y, reflected.z), (1.0, 1.0, 0.0));
+
+    let mid = lerp3(0.5, a, b);
+    assert_eq!((mid.x, mid.y, mid.z), (0.5, 1.0, 1.0));
+}
+
+#[test]
+fn vector3_coordinate_system_is_orthonormal_and_right_handed() {
+    let n = Vector3::new(1.0_f32, 0.0, 0.0);
+    let (v2, v3) = n.coordinate_system();
+
+    // All three axes are mutually perpendicular...
+    assert!(n.dot(v2).abs() < 1e-6);
+    assert!(n.dot(v3).abs() < 1e-6);
+    assert!(v2.dot(v3).abs() < 1e-6);
+
+    // ...and unit length.
+    assert!((v2.length() - 1.0).abs() < 1e-6);
+    assert!((v3.length() - 1.0).abs() < 1e-6);
+
+    // Right-handed: n x v2 == v3.
+    let cross = n.cross(v2);
+    assert!((cross.x - v3.x).abs() < 1e-6);
+    assert!((cross.y - v3.y).abs() < 1e-6);
+    assert!((cross.z - v3.z).abs() < 1e-6);
+}
+
+#[test]
+fn vector4_distance_reflect_and_lerp4() {
+    let a = Vector4::new(0.0, 0.0, 0.0, 0.0);
+    let b = Vector4::new(1.0, 2.0, 2.0, 0.0);
+    assert_eq!(a.distance(b), 3.0);
+    assert_eq!(a.distance_squared(b), 9.0);
+
+    let incoming = Vector4::new(1.0, -1.0, 0.0, 0.0);
+    let normal = Vector4::new(0.0, 1.0, 0.0, 0.0);
+    let reflected = incoming.reflect(normal);
+    assert_eq!((reflected.x, reflected.y, reflected.z, reflected.w), (1.0, 1.0, 0.0, 0.0));
+
+    let mid = lerp4(0.5, a, b);
+    assert_eq!((mid.x, mid.y, mid.z, mid.w), (0.5, 1.0, 1.0, 0.0));
+}
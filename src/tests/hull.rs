@@ -0,0 +1,43 @@
+use super::super::math;
+use math::hull::convex_hull;
+use math::vector::Vector2;
+
+#[test]
+fn convex_hull_collinear_collapses_to_endpoints() {
+    let points = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(2.0, 0.0),
+        Vector2::new(3.0, 0.0),
+    ];
+    let hull = convex_hull(&points);
+    assert_eq!(hull.len(), 2);
+    assert_eq!((hull[0].x, hull[0].y), (0.0, 0.0));
+    assert_eq!((hull[1].x, hull[1].y), (3.0, 0.0));
+}
+
+#[test]
+fn convex_hull_drops_exact_duplicates() {
+    let points = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+    ];
+    let hull = convex_hull(&points);
+    assert_eq!(hull.len(), 3);
+}
+
+#[test]
+fn convex_hull_square_keeps_only_corners() {
+    // The point in the middle of the bottom edge should be dropped by the hull.
+    let points = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(2.0, 0.0),
+        Vector2::new(2.0, 2.0),
+        Vector2::new(0.0, 2.0),
+    ];
+    let hull = convex_hull(&points);
+    assert_eq!(hull.len(), 4);
+}
@@ -0,0 +1,44 @@
+use super::super::math;
+use math::matrix::Matrix4;
+use math::vector::{Vector3, Vector4};
+
+#[test]
+fn matrix4_inverse_round_trips_to_identity() {
+    let m = Matrix4 {
+        m: [
+            [2.0, 0.0, 0.0, 3.0],
+            [0.0, 1.0, 0.0, 1.0],
+            [0.0, 0.0, 4.0, -2.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ],
+    };
+    let inv = m.inverse().expect("matrix should be invertible");
+    let product = m * inv;
+    let identity = Matrix4::<f64>::identity();
+    for row in 0..4 {
+        for col in 0..4 {
+            assert!((product.m[row][col] - identity.m[row][col]).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn matrix4_inverse_none_for_singular_matrix() {
+    // Second row is a multiple of the first, so this matrix is singular.
+    let m = Matrix4 {
+        m: [
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 4.0, 6.0, 8.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+        ],
+    };
+    assert!(m.inverse().is_none());
+}
+
+#[test]
+fn matrix4_translation_moves_point() {
+    let t = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0));
+    let p = t * Vector4::new(0.0, 0.0, 0.0, 1.0);
+    assert_eq!((p.x, p.y, p.z, p.w), (1.0, 2.0, 3.0, 1.0));
+}
@@ -0,0 +1,32 @@
+use super::super::graph;
+use eframe::egui::Color32;
+use graph::style::{wire_style_for, DataTypeTrait, WireStyle};
+
+#[derive(Clone, Copy, PartialEq)]
+enum TestType {
+    Number,
+    Logic,
+}
+
+impl DataTypeTrait<()> for TestType {
+    fn data_type_color(&self, _user_state: &()) -> Color32 {
+        Color32::WHITE
+    }
+
+    fn wire_style(&self) -> WireStyle {
+        match self {
+            TestType::Number => WireStyle::Bezier,
+            TestType::Logic => WireStyle::AxisAligned,
+        }
+    }
+}
+
+#[test]
+fn matching_types_defer_to_the_types_own_wire_style() {
+    assert_eq!(wire_style_for(&TestType::Logic, &TestType::Logic), WireStyle::AxisAligned);
+}
+
+#[test]
+fn mismatched_types_fall_back_to_bezier() {
+    assert_eq!(wire_style_for(&TestType::Logic, &TestType::Number), WireStyle::Bezier);
+}
@@ -0,0 +1,111 @@
+use super::super::math;
+use math::vector::{Vector2, Vector3, Vector4};
+
+#[test]
+fn vector2_cross_is_positive_for_a_counter_clockwise_turn() {
+    let a = Vector2::new(1.0, 0.0);
+    let b = Vector2::new(0.0, 1.0);
+    assert_eq!(a.cross(b), 1.0);
+    assert_eq!(b.cross(a), -1.0);
+}
+
+#[test]
+fn vector2_cross_magnitude_is_twice_the_triangle_area() {
+    // Right triangle with legs 3 and 4: area = 6, so |cross| should be 12.
+    let a = Vector2::new(3.0, 0.0);
+    let b = Vector2::new(0.0, 4.0);
+    assert_eq!(a.cross(b).abs(), 12.0);
+}
+
+#[test]
+fn vector2_perp_rotates_ninety_degrees_counter_clockwise() {
+    let v = Vector2::new(1.0, 0.0);
+    let rotated = v.perp();
+    assert_eq!((rotated.x, rotated.y), (0.0, 1.0));
+    // Rotating four times should return to the original vector.
+    let full_turn = v.perp().perp().perp().perp();
+    assert_eq!((full_turn.x, full_turn.y), (v.x, v.y));
+}
+
+#[test]
+fn vector3_cross_is_perpendicular_to_both_operands() {
+    let a = Vector3::new(1.0, 0.0, 0.0);
+    let b = Vector3::new(0.0, 1.0, 0.0);
+    let cross = a.cross(b);
+    assert_eq!((cross.x, cross.y, cross.z), (0.0, 0.0, 1.0));
+    assert_eq!(cross.dot(a), 0.0);
+    assert_eq!(cross.dot(b), 0.0);
+}
+
+#[test]
+fn vector3_cross_is_anticommutative() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(-1.0, 0.5, 2.0);
+    let ab = a.cross(b);
+    let ba = b.cross(a);
+    assert_eq!((ab.x, ab.y, ab.z), (-ba.x, -ba.y, -ba.z));
+}
+
+#[test]
+fn vector4_arithmetic_matches_component_wise_expectation() {
+    let a = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let b = Vector4::new(5.0, 6.0, 7.0, 8.0);
+
+    let sum = a + b;
+    assert_eq!((sum.x, sum.y, sum.z, sum.w), (6.0, 8.0, 10.0, 12.0));
+
+    let diff = b - a;
+    assert_eq!((diff.x, diff.y, diff.z, diff.w), (4.0, 4.0, 4.0, 4.0));
+
+    let scaled = a * 2.0;
+    assert_eq!((scaled.x, scaled.y, scaled.z, scaled.w), (2.0, 4.0, 6.0, 8.0));
+
+    let halved = scaled / 2.0;
+    assert_eq!((halved.x, halved.y, halved.z, halved.w), (a.x, a.y, a.z, a.w));
+
+    let componentwise_mul = a * b;
+    assert_eq!(
+        (componentwise_mul.x, componentwise_mul.y, componentwise_mul.z, componentwise_mul.w),
+        (5.0, 12.0, 21.0, 32.0)
+    );
+
+    let componentwise_div = componentwise_mul / a;
+    assert_eq!(
+        (componentwise_div.x, componentwise_div.y, componentwise_div.z, componentwise_div.w),
+        (b.x, b.y, b.z, b.w)
+    );
+}
+
+#[test]
+fn vector4_dot_length_normalize_and_abs() {
+    let v = Vector4::new(3.0, -4.0, 0.0, 0.0);
+    assert_eq!(v.dot(v), 25.0);
+    assert_eq!(v.length(), 5.0);
+
+    let normalized = v.normalize();
+    assert!((normalized.length() - 1.0).abs() < 1e-6);
+
+    let abs = v.abs();
+    assert_eq!((abs.x, abs.y, abs.z, abs.w), (3.0, 4.0, 0.0, 0.0));
+}
+
+#[test]
+fn vector4_index_reads_and_writes_each_component() {
+    let mut v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!((v[0], v[1], v[2], v[3]), (1.0, 2.0, 3.0, 4.0));
+
+    v[2] = 9.0;
+    assert_eq!(v.z, 9.0);
+}
+
+#[test]
+fn vector4_min_max_take_the_componentwise_extreme() {
+    let a = Vector4::new(1, 5, 3, 8);
+    let b = Vector4::new(4, 2, 3, 1);
+
+    let min = a.min(b);
+    assert_eq!((min.x, min.y, min.z, min.w), (1, 2, 3, 1));
+
+    let max = a.max(b);
+    assert_eq!((max.x, max.y, max.z, max.w), (4, 5, 3, 8));
+}
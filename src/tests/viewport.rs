@@ -0,0 +1,42 @@
+use eframe::egui;
+
+use super::super::graph;
+use graph::viewport::ViewportState;
+
+#[test]
+fn to_screen_and_to_world_are_inverses() {
+    let viewport = ViewportState {
+        scale: 2.0,
+        offset: egui::Vec2::new(10.0, -5.0),
+    };
+
+    let world = egui::Pos2::new(3.0, 7.0);
+    let screen = viewport.to_screen(world);
+    let back = viewport.to_world(screen);
+
+    assert!((back.x - world.x).abs() < 1e-4);
+    assert!((back.y - world.y).abs() < 1e-4);
+}
+
+#[test]
+fn default_viewport_is_identity() {
+    let viewport = ViewportState::default();
+    let world = egui::Pos2::new(4.0, -9.0);
+    let screen = viewport.to_screen(world);
+    assert_eq!((screen.x, screen.y), (world.x, world.y));
+}
+
+#[test]
+fn load_falls_back_to_default_and_store_round_trips() {
+    let ctx = egui::Context::default();
+    let id = egui::Id::new("test-viewport");
+
+    assert_eq!(ViewportState::load(&ctx, id), ViewportState::default());
+
+    let saved = ViewportState {
+        scale: 1.5,
+        offset: egui::Vec2::new(1.0, 2.0),
+    };
+    saved.store(&ctx, id);
+    assert_eq!(ViewportState::load(&ctx, id), saved);
+}